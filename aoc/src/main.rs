@@ -0,0 +1,109 @@
+use clap::{Parser, Subcommand};
+use std::{fs, path::PathBuf, process::ExitCode};
+
+use aoc_solution::{print_part, print_parts};
+
+mod puzzles;
+
+#[derive(Parser)]
+#[command(name = "aoc")]
+/// Run any year's Advent of Code solutions
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a day's puzzle and print the answers
+    Run {
+        #[arg(short, long)]
+        /// The year of the event
+        year: u16,
+
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        /// The day of the month (1-25)
+        day: u8,
+
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        /// Only solve this part (1 or 2), both by default
+        part: Option<u8>,
+
+        #[arg(short, long)]
+        /// Read the puzzle input from this file instead of the day's own
+        input: Option<PathBuf>,
+    },
+    /// List the puzzles that can be run
+    List {
+        #[arg(short, long)]
+        /// Only list puzzles from this year
+        year: Option<u16>,
+    },
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: Cli) -> Result<(), String> {
+    match args.command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+        } => {
+            let puzzle = puzzles::find(year, day).ok_or_else(|| {
+                let days = days_in(year);
+                if days.is_empty() {
+                    format!("no solutions for {year}")
+                } else {
+                    format!("no solution for {year} day {day}, try one of {days}")
+                }
+            })?;
+
+            let path = input.unwrap_or_else(|| PathBuf::from(puzzle.input));
+            let input = fs::read_to_string(&path)
+                .map_err(|err| format!("could not read {}: {err}", path.display()))?;
+
+            match part {
+                Some(part) => {
+                    if !print_part(&puzzle, part, &input) {
+                        return Err(format!("{year} day {day} has no part {part}"));
+                    }
+                }
+                None => print_parts(&puzzle, &input),
+            }
+        }
+        Command::List { year } => {
+            let mut years: Vec<u16> = puzzles::all().iter().map(|p| p.year).collect();
+            years.dedup();
+
+            for listed in years
+                .into_iter()
+                .filter(|y| year.is_none_or(|year| year == *y))
+            {
+                println!("{listed}: {}", days_in(listed));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The days that can be run for a year, comma separated
+fn days_in(year: u16) -> String {
+    let days: Vec<String> = puzzles::all()
+        .iter()
+        .filter(|puzzle| puzzle.year == year)
+        .map(|puzzle| puzzle.day.to_string())
+        .collect();
+
+    days.join(", ")
+}
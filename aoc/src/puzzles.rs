@@ -0,0 +1,91 @@
+use aoc_solution::Puzzle;
+
+use intcomputer::days::{day11, day13, day2, day5, day7, day9};
+
+/// Every puzzle the runner knows about, in year then day order
+pub fn all() -> Vec<Puzzle> {
+    let mut puzzles = vec![
+        day2::PUZZLE,
+        day5::PUZZLE,
+        day7::PUZZLE,
+        day9::PUZZLE,
+        day11::PUZZLE,
+        day13::PUZZLE,
+    ];
+    puzzles.extend_from_slice(aoc2020::PUZZLES);
+    puzzles.extend_from_slice(aoc2021::PUZZLES);
+    puzzles.extend([
+        aoc2022_day1::PUZZLE,
+        aoc2022_day2::PUZZLE,
+        aoc2022_day3::PUZZLE,
+        aoc2023_day1::PUZZLE,
+        aoc2023_day2::PUZZLE,
+        aoc2023_day3::PUZZLE,
+        aoc2023_day4::PUZZLE,
+        aoc2023_day5::PUZZLE,
+        aoc2023_day6::PUZZLE,
+        aoc2023_day7::PUZZLE,
+        aoc2023_day8::PUZZLE,
+        aoc2023_day9::PUZZLE,
+        aoc2023_day10::PUZZLE,
+        aoc2023_day11::PUZZLE,
+        aoc2023_day12::PUZZLE,
+        aoc2023_day13::PUZZLE,
+        aoc2023_day14::PUZZLE,
+        aoc2023_day15::PUZZLE,
+        aoc2023_day16::PUZZLE,
+        aoc2023_day17::PUZZLE,
+        aoc2023_day18::PUZZLE,
+        aoc2023_day19::PUZZLE,
+        aoc2023_day20::PUZZLE,
+        aoc2023_day21::PUZZLE,
+        aoc2023_day22::PUZZLE,
+        aoc2023_day23::PUZZLE,
+        aoc2023_day24::PUZZLE,
+        aoc2023_day25::PUZZLE,
+        aoc2024_day1::PUZZLE,
+        aoc2024_day2::PUZZLE,
+        aoc2024_day3::PUZZLE,
+        aoc2024_day4::PUZZLE,
+        aoc2024_day5::PUZZLE,
+        aoc2024_day7::PUZZLE,
+        aoc2024_day8::PUZZLE,
+        aoc2024_day9::PUZZLE,
+        aoc2024_day10::PUZZLE,
+        aoc2024_day11::PUZZLE,
+        aoc2024_day13::PUZZLE,
+        aoc2024_day14::PUZZLE,
+        aoc2024_day15::PUZZLE,
+        aoc2024_day16::PUZZLE,
+        aoc2024_day17::PUZZLE,
+        aoc2024_day18::PUZZLE,
+        aoc2024_day19::PUZZLE,
+        aoc2024_day20::PUZZLE,
+        aoc2024_day21::PUZZLE,
+        aoc2024_day22::PUZZLE,
+        aoc2024_day23::PUZZLE,
+        aoc2024_day24::PUZZLE,
+        aoc2024_day25::PUZZLE,
+    ]);
+
+    puzzles
+}
+
+/// Will return none if that day isn't solved or its crate isn't in the workspace
+pub fn find(year: u16, day: u8) -> Option<Puzzle> {
+    all()
+        .into_iter()
+        .find(|puzzle| puzzle.year == year && puzzle.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn puzzles_are_in_order_without_repeats() {
+        let keys: Vec<(u16, u8)> = all().iter().map(|p| (p.year, p.day)).collect();
+
+        assert!(keys.windows(2).all(|pair| pair[0] < pair[1]), "{keys:?}");
+    }
+}
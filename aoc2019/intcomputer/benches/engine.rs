@@ -0,0 +1,52 @@
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use intcomputer::{asm::assemble, computer::Computer};
+
+/// Sums the squares below the input, five instructions a number with relative, position and
+/// immediate parameters. Assembled here so the bench doesn't need a puzzle input
+const SQUARES: &str = "
+        IN -> [n]
+        ARB #100
+    loop:
+        MUL [i], [i] -> [rb+0]
+        ADD [sum], [rb+0] -> [sum]
+        ADD [i], #1 -> [i]
+        LT [i], [n] -> [rb+1]
+        JT [rb+1], loop
+        OUT [sum]
+        HALT
+    n:   DATA 0
+    i:   DATA 0
+    sum: DATA 0
+";
+
+const NUMBERS: i64 = 200_000;
+
+fn squares(c: &mut Criterion) {
+    let tape = assemble(SQUARES).expect("bench tape should assemble");
+    let mut computer = Computer::new(&tape);
+    computer.push_input(NUMBERS);
+    let expected = (NUMBERS - 1) * NUMBERS * (2 * NUMBERS - 1) / 6;
+    assert_eq!(computer.run_to_halt().unwrap(), vec![expected]);
+
+    let mut group = c.benchmark_group("sum of squares");
+    // Throughput is per instruction run
+    group.throughput(Throughput::Elements(5 * NUMBERS as u64 + 4));
+    group.bench_function("interpreter", |b| {
+        b.iter(|| {
+            let mut computer = Computer::new(&tape);
+            computer.push_input(NUMBERS);
+            computer.run_to_halt().unwrap()
+        })
+    });
+    group.bench_function("decode cache", |b| {
+        b.iter(|| {
+            let mut computer = Computer::new(&tape).with_decode_cache();
+            computer.push_input(NUMBERS);
+            computer.run_to_halt().unwrap()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, squares);
+criterion_main!(benches);
@@ -0,0 +1,112 @@
+use std::io::{self, BufRead, Write};
+
+use crate::computer::{Computer, ComputerError, RunState};
+
+/// Everything a program printed before it stopped to wait for input or halted
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Prompt {
+    pub text: String,
+    /// Outputs that aren't ascii, usually the answer
+    pub values: Vec<i64>,
+    pub halted: bool,
+}
+
+/// Talks to a computer in lines of text instead of raw values
+pub struct AsciiComputer {
+    computer: Computer,
+    /// Print text to stdout as it comes out as well as collecting it
+    echo: bool,
+}
+
+impl AsciiComputer {
+    pub fn new(computer: Computer) -> Self {
+        Self {
+            computer,
+            echo: false,
+        }
+    }
+
+    pub fn from_tape(tape: &[i64]) -> Self {
+        Self::new(Computer::new(tape))
+    }
+
+    /// Print text to stdout as the program produces it
+    pub fn streaming(mut self) -> Self {
+        self.echo = true;
+        self
+    }
+
+    pub fn computer(&self) -> &Computer {
+        &self.computer
+    }
+
+    pub fn into_computer(self) -> Computer {
+        self.computer
+    }
+
+    /// Queue a line of input, the newline is added
+    pub fn send_line(&mut self, line: &str) {
+        self.computer.push_inputs(line.bytes().map(i64::from));
+        self.computer.push_input(b'\n' as i64);
+    }
+
+    /// Run until the program wants input that hasn't been sent or halts
+    pub fn read_until_prompt(&mut self) -> Result<Prompt, ComputerError> {
+        let mut prompt = Prompt::default();
+
+        loop {
+            match self.computer.run_until_io()? {
+                RunState::Output(value) => match u8::try_from(value) {
+                    Ok(byte) if byte.is_ascii() => {
+                        let c = byte as char;
+                        if self.echo {
+                            print!("{c}");
+                        }
+                        prompt.text.push(c);
+                    }
+                    _ => prompt.values.push(value),
+                },
+                RunState::NeedsInput => break,
+                RunState::Halted => {
+                    prompt.halted = true;
+                    break;
+                }
+            }
+        }
+
+        if self.echo {
+            io::stdout().flush().expect("stdout should flush");
+        }
+
+        Ok(prompt)
+    }
+
+    /// Play by hand, echoing the program to stdout and sending each line read from input until
+    /// it halts or input runs out. Non ascii outputs are printed on their own line
+    pub fn play(&mut self, input: impl BufRead) -> Result<(), ComputerError> {
+        let echo = self.echo;
+        self.echo = true;
+
+        let mut lines = input.lines();
+        let result = loop {
+            let prompt = match self.read_until_prompt() {
+                Ok(prompt) => prompt,
+                Err(err) => break Err(err),
+            };
+            for value in prompt.values {
+                println!("\n[output {value}]");
+            }
+
+            if prompt.halted {
+                break Ok(());
+            }
+            match lines.next() {
+                Some(Ok(line)) => self.send_line(&line),
+                _ => break Ok(()),
+            }
+        };
+
+        self.echo = echo;
+        result
+    }
+}
@@ -0,0 +1,364 @@
+use std::{collections::HashMap, fmt};
+
+/// Something wrong with the source, lines count from 1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssembleError {
+    UnknownMnemonic {
+        line: usize,
+        mnemonic: String,
+    },
+    BadOperand {
+        line: usize,
+        operand: String,
+    },
+    WrongOperandCount {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    WriteToImmediate {
+        line: usize,
+    },
+    UnknownLabel {
+        line: usize,
+        label: String,
+    },
+    DuplicateLabel {
+        line: usize,
+        label: String,
+    },
+    /// An `0012:` address prefix that doesn't match where the line actually lands
+    AddressMismatch {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl fmt::Display for AssembleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AssembleError::UnknownMnemonic { line, mnemonic } => {
+                write!(f, "line {line}: unknown mnemonic {mnemonic}")
+            }
+            AssembleError::BadOperand { line, operand } => {
+                write!(f, "line {line}: can't parse operand {operand}")
+            }
+            AssembleError::WrongOperandCount {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {line}: expected {expected} operands, found {found}"
+            ),
+            AssembleError::WriteToImmediate { line } => {
+                write!(f, "line {line}: can't write to an immediate")
+            }
+            AssembleError::UnknownLabel { line, label } => {
+                write!(f, "line {line}: unknown label {label}")
+            }
+            AssembleError::DuplicateLabel { line, label } => {
+                write!(f, "line {line}: label {label} already defined")
+            }
+            AssembleError::AddressMismatch {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {line}: address {found} given but line is at {expected}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for AssembleError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Position = 0,
+    Immediate = 1,
+    Relative = 2,
+}
+
+/// A number or a label that still needs resolving
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Value {
+    Number(i64),
+    Label(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Operand {
+    mode: Mode,
+    value: Value,
+}
+
+/// One line with a mnemonic on it
+struct Statement<'a> {
+    line: usize,
+    mnemonic: &'a str,
+    operands: Vec<String>,
+}
+
+/// (opcode, operand count, whether the last operand is written to)
+fn opcode(mnemonic: &str) -> Option<(i64, usize, bool)> {
+    let op = match mnemonic.to_ascii_uppercase().as_str() {
+        "ADD" => (1, 3, true),
+        "MULT" | "MUL" => (2, 3, true),
+        "IN" => (3, 1, true),
+        "OUT" => (4, 1, false),
+        "JT" => (5, 2, false),
+        "JF" => (6, 2, false),
+        "LT" => (7, 3, true),
+        "EQ" => (8, 3, true),
+        "ARB" => (9, 1, false),
+        "HALT" => (99, 0, false),
+        _ => return None,
+    };
+
+    Some(op)
+}
+
+/// Turn source into a tape. Each line is `[label:] [mnemonic operands] [; comment]`, the
+/// mnemonics are the ones printed by the disassembler and operands are `#n` immediate, `[n]`
+/// position or `[rb+n]` relative, where n can also be a label and `[rb]` is `[rb+0]`. A bare
+/// number or label is immediate, and `DATA a, b, c` puts the values straight onto the tape
+/// Example: assemble("loop: IN -> [rb+1]\nJT #1, loop")
+pub fn assemble(source: &str) -> Result<Vec<i64>, AssembleError> {
+    let mut labels = HashMap::new();
+    let mut statements = Vec::new();
+    let mut address = 0;
+
+    // First pass finds where every label lands
+    for (i, text) in source.lines().enumerate() {
+        let line = i + 1;
+        let mut rest = text.split(';').next().expect("split has a first").trim();
+
+        while let Some((prefix, after)) = split_label(rest) {
+            if prefix.chars().all(|c| c.is_ascii_digit()) {
+                let found = prefix.parse().map_err(|_| AssembleError::BadOperand {
+                    line,
+                    operand: prefix.to_string(),
+                })?;
+                if found != address {
+                    return Err(AssembleError::AddressMismatch {
+                        line,
+                        expected: address,
+                        found,
+                    });
+                }
+            } else if labels.insert(prefix.to_string(), address).is_some() {
+                return Err(AssembleError::DuplicateLabel {
+                    line,
+                    label: prefix.to_string(),
+                });
+            }
+            rest = after;
+        }
+
+        if rest.is_empty() {
+            continue;
+        }
+
+        let (mnemonic, operands) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+        // The arrow before a destination is only for reading, it separates like a comma
+        let operands: Vec<String> = operands
+            .replace("->", ",")
+            .split(',')
+            .map(str::trim)
+            .filter(|operand| !operand.is_empty())
+            .map(str::to_string)
+            .collect();
+
+        address += if mnemonic.eq_ignore_ascii_case("DATA") {
+            operands.len()
+        } else {
+            let (_, count, _) = opcode(mnemonic).ok_or_else(|| AssembleError::UnknownMnemonic {
+                line,
+                mnemonic: mnemonic.to_string(),
+            })?;
+            if operands.len() != count {
+                return Err(AssembleError::WrongOperandCount {
+                    line,
+                    expected: count,
+                    found: operands.len(),
+                });
+            }
+            count + 1
+        };
+
+        statements.push(Statement {
+            line,
+            mnemonic,
+            operands,
+        });
+    }
+
+    // Second pass writes the tape now every label is known
+    let mut tape = Vec::with_capacity(address);
+    for statement in statements {
+        let line = statement.line;
+        let operands = statement
+            .operands
+            .iter()
+            .map(|operand| parse_operand(operand, line))
+            .collect::<Result<Vec<_>, _>>()?;
+        let resolve = |value: &Value| match value {
+            Value::Number(n) => Ok(*n),
+            Value::Label(label) => {
+                labels
+                    .get(label)
+                    .map(|address| *address as i64)
+                    .ok_or_else(|| AssembleError::UnknownLabel {
+                        line,
+                        label: label.clone(),
+                    })
+            }
+        };
+
+        if statement.mnemonic.eq_ignore_ascii_case("DATA") {
+            for operand in operands.iter() {
+                if operand.mode != Mode::Immediate {
+                    return Err(AssembleError::BadOperand {
+                        line,
+                        operand: statement.operands.join(", "),
+                    });
+                }
+                tape.push(resolve(&operand.value)?);
+            }
+            continue;
+        }
+
+        let (op, _, writes) = opcode(statement.mnemonic).expect("checked in first pass");
+        if writes
+            && operands
+                .last()
+                .is_some_and(|dest| dest.mode == Mode::Immediate)
+        {
+            return Err(AssembleError::WriteToImmediate { line });
+        }
+
+        let modes = operands
+            .iter()
+            .enumerate()
+            .map(|(i, operand)| operand.mode as i64 * 10i64.pow(i as u32 + 2))
+            .sum::<i64>();
+        tape.push(op + modes);
+        for operand in operands.iter() {
+            tape.push(resolve(&operand.value)?);
+        }
+    }
+
+    Ok(tape)
+}
+
+/// Split `label: rest` off the front of a line
+fn split_label(text: &str) -> Option<(&str, &str)> {
+    let (prefix, rest) = text.split_once(':')?;
+    let prefix = prefix.trim();
+    let is_name = !prefix.is_empty() && prefix.chars().all(|c| c.is_alphanumeric() || c == '_');
+
+    is_name.then(|| (prefix, rest.trim()))
+}
+
+fn parse_operand(operand: &str, line: usize) -> Result<Operand, AssembleError> {
+    let bad = || AssembleError::BadOperand {
+        line,
+        operand: operand.to_string(),
+    };
+
+    let (mode, value) = if let Some(inner) = operand.strip_prefix('[') {
+        let inner = inner.strip_suffix(']').ok_or_else(bad)?.trim();
+        // Only rb on its own or followed by a sign is relative, [rbuf] is the label rbuf
+        match inner.strip_prefix("rb").map(str::trim) {
+            Some("") => (Mode::Relative, "0"),
+            Some(offset) if offset.starts_with(['+', '-']) => {
+                let offset = offset.strip_prefix('+').unwrap_or(offset).trim();
+                (Mode::Relative, offset)
+            }
+            _ => (Mode::Position, inner),
+        }
+    } else {
+        (
+            Mode::Immediate,
+            operand.strip_prefix('#').unwrap_or(operand),
+        )
+    };
+
+    let value = match value.parse::<i64>() {
+        Ok(n) => Value::Number(n),
+        Err(_) if value.starts_with(|c: char| c.is_alphabetic() || c == '_') => {
+            Value::Label(value.to_string())
+        }
+        Err(_) => return Err(bad()),
+    };
+
+    Ok(Operand { mode, value })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_operands() {
+        let number = |mode, n| Operand {
+            mode,
+            value: Value::Number(n),
+        };
+
+        assert_eq!(parse_operand("#-5", 1), Ok(number(Mode::Immediate, -5)));
+        assert_eq!(parse_operand("7", 1), Ok(number(Mode::Immediate, 7)));
+        assert_eq!(parse_operand("[104]", 1), Ok(number(Mode::Position, 104)));
+        assert_eq!(parse_operand("[rb+3]", 1), Ok(number(Mode::Relative, 3)));
+        assert_eq!(parse_operand("[rb-3]", 1), Ok(number(Mode::Relative, -3)));
+        assert_eq!(parse_operand("[rb]", 1), Ok(number(Mode::Relative, 0)));
+        assert_eq!(
+            parse_operand("[rbuf]", 1),
+            Ok(Operand {
+                mode: Mode::Position,
+                value: Value::Label("rbuf".to_string())
+            })
+        );
+        assert_eq!(
+            parse_operand("[end]", 1),
+            Ok(Operand {
+                mode: Mode::Position,
+                value: Value::Label("end".to_string())
+            })
+        );
+        assert!(parse_operand("[12", 1).is_err());
+    }
+
+    #[test]
+    fn reports_errors_with_lines() {
+        assert_eq!(
+            assemble("HALT\nJMP #1"),
+            Err(AssembleError::UnknownMnemonic {
+                line: 2,
+                mnemonic: "JMP".to_string()
+            })
+        );
+        assert_eq!(
+            assemble("ADD #1, #2 -> #3"),
+            Err(AssembleError::WriteToImmediate { line: 1 })
+        );
+        assert_eq!(
+            assemble("JT #1, nowhere"),
+            Err(AssembleError::UnknownLabel {
+                line: 1,
+                label: "nowhere".to_string()
+            })
+        );
+        assert_eq!(
+            assemble("OUT #1\n0001: HALT"),
+            Err(AssembleError::AddressMismatch {
+                line: 2,
+                expected: 2,
+                found: 1
+            })
+        );
+    }
+}
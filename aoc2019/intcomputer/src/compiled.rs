@@ -0,0 +1,194 @@
+use std::collections::VecDeque;
+
+use crate::{computer::RunState, memory::Memory};
+
+/// Only addresses below this are compiled, anything further always goes through step
+const CACHE_LIMIT: usize = 1 << 20;
+
+/// An instruction with its opcode and modes already pulled apart
+#[derive(Debug, Clone, Copy)]
+struct Op {
+    code: u8,
+    len: u8,
+    modes: [u8; 3],
+    args: [i64; 3],
+}
+
+/// Compiled ops by the address they start at. Writes to any cell an op was compiled from throw
+/// the op away so self modifying tapes still run correctly
+#[derive(Debug, Clone, Default)]
+pub(crate) struct OpCache {
+    ops: Vec<Option<Op>>,
+    /// Whether a compiled op might include the cell, so most writes can skip the search
+    covered: Vec<bool>,
+}
+
+impl OpCache {
+    fn get_or_compile(&mut self, mem: &Memory, address: usize) -> Option<Op> {
+        if let Some(Some(op)) = self.ops.get(address) {
+            return Some(*op);
+        }
+
+        let op = compile(mem, address)?;
+        if address + (op.len as usize) < CACHE_LIMIT {
+            let end = address + op.len as usize;
+            if end > self.ops.len() {
+                self.ops.resize(end, None);
+                self.covered.resize(end, false);
+            }
+            self.ops[address] = Some(op);
+            self.covered[address..end].fill(true);
+        }
+
+        Some(op)
+    }
+
+    /// Forget any op compiled from address
+    pub(crate) fn invalidate(&mut self, address: usize) {
+        if !self.covered.get(address).copied().unwrap_or(false) {
+            return;
+        }
+
+        for start in address.saturating_sub(3)..=address {
+            if self.ops[start].is_some_and(|op| start + op.len as usize > address) {
+                self.ops[start] = None;
+            }
+        }
+    }
+}
+
+/// None for anything the fast loop doesn't handle, step will report the error
+fn compile(mem: &Memory, address: usize) -> Option<Op> {
+    let raw = mem.read(address);
+    let (code, params, writes) = match raw % 100 {
+        99 => (99, 0, false),
+        code @ (1 | 2 | 7 | 8) => (code, 3, true),
+        3 => (3, 1, true),
+        4 => (4, 1, false),
+        code @ (5 | 6) => (code, 2, false),
+        9 => (9, 1, false),
+        _ => return None,
+    };
+
+    let mut op = Op {
+        code: code as u8,
+        len: params + 1,
+        modes: [0; 3],
+        args: [0; 3],
+    };
+    let mut modes = raw / 100;
+    for i in 0..params as usize {
+        let mode = modes % 10;
+        let arg = mem.read(address + i + 1);
+        if mode > 2 || (mode == 0 && arg < 0) {
+            return None;
+        }
+        op.modes[i] = mode as u8;
+        op.args[i] = arg;
+        modes /= 10;
+    }
+    if writes && op.modes[params as usize - 1] == 1 {
+        return None;
+    }
+
+    Some(op)
+}
+
+/// The computer's registers while running compiled, written back when the loop exits
+pub(crate) struct Registers<'a> {
+    pub mem: &'a mut Memory,
+    pub instruction_ptr: &'a mut usize,
+    pub relative_base: &'a mut i64,
+    pub inputs: &'a mut VecDeque<i64>,
+}
+
+/// Run compiled ops until input is needed, an output is made or the tape halts. Returns None
+/// without running the instruction at the pointer if it can't be run compiled, so the caller
+/// should run it with step to get the error
+pub(crate) fn run(cache: &mut OpCache, registers: Registers) -> Option<RunState> {
+    let Registers {
+        mem,
+        instruction_ptr,
+        relative_base,
+        inputs,
+    } = registers;
+    let mut ip = *instruction_ptr;
+    let mut rb = *relative_base;
+
+    let state = loop {
+        let Some(op) = cache.get_or_compile(mem, ip) else {
+            break None;
+        };
+
+        let address = |i: usize| -> Option<usize> {
+            match op.modes[i] {
+                0 => Some(op.args[i] as usize),
+                _ => usize::try_from(rb + op.args[i]).ok(),
+            }
+        };
+        let read = |mem: &Memory, i: usize| -> Option<i64> {
+            match op.modes[i] {
+                1 => Some(op.args[i]),
+                _ => address(i).map(|address| mem.read(address)),
+            }
+        };
+        macro_rules! read_or_bail {
+            ($i:expr) => {
+                match read(mem, $i) {
+                    Some(value) => value,
+                    None => break None,
+                }
+            };
+        }
+        macro_rules! write_or_bail {
+            ($i:expr, $value:expr) => {{
+                let Some(dest) = address($i) else {
+                    break None;
+                };
+                mem.write(dest, $value);
+                cache.invalidate(dest);
+            }};
+        }
+
+        match op.code {
+            1 => write_or_bail!(2, read_or_bail!(0) + read_or_bail!(1)),
+            2 => write_or_bail!(2, read_or_bail!(0) * read_or_bail!(1)),
+            3 => {
+                let Some(input) = inputs.front().copied() else {
+                    break Some(RunState::NeedsInput);
+                };
+                write_or_bail!(0, input);
+                inputs.pop_front();
+            }
+            4 => {
+                let value = read_or_bail!(0);
+                ip += 2;
+                break Some(RunState::Output(value));
+            }
+            5 | 6 => {
+                let cond = read_or_bail!(0) != 0;
+                if cond == (op.code == 5) {
+                    match usize::try_from(read_or_bail!(1)) {
+                        // Jumping to itself runs on like step does
+                        Ok(target) if target != ip => {
+                            ip = target;
+                            continue;
+                        }
+                        Ok(_) => {}
+                        Err(_) => break None,
+                    }
+                }
+            }
+            7 => write_or_bail!(2, (read_or_bail!(0) < read_or_bail!(1)) as i64),
+            8 => write_or_bail!(2, (read_or_bail!(0) == read_or_bail!(1)) as i64),
+            9 => rb += read_or_bail!(0),
+            _ => break Some(RunState::Halted),
+        }
+
+        ip += op.len as usize;
+    };
+
+    *instruction_ptr = ip;
+    *relative_base = rb;
+    state
+}
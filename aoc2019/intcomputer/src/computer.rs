@@ -1,10 +1,19 @@
-use std::sync::mpsc::{Receiver, Sender};
+use std::{
+    collections::VecDeque,
+    fmt,
+    sync::mpsc::{Receiver, Sender},
+};
 
-const MAX_MEMORY: usize = 10_000;
-type Memory = [i64; MAX_MEMORY];
+use crate::{
+    compiled::{self, OpCache, Registers},
+    disasm::format_instruction,
+    memory::Memory,
+    state::ComputerState,
+    trace::{TraceRecord, Tracer},
+};
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-enum Parameter {
+pub(crate) enum Parameter {
     /// Must be followed to a location
     Position(usize),
     /// Is the value to be used
@@ -13,8 +22,8 @@ enum Parameter {
     Relative(i64),
 }
 
-#[derive(Debug, PartialEq, Eq)]
-enum Instruction {
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum Instruction {
     /// 01 a + b -> c
     Add(Parameter, Parameter, Parameter),
     /// 02 a * b -> c
@@ -35,200 +44,615 @@ enum Instruction {
     AdjustRelativeBase(Parameter),
 }
 
+impl Instruction {
+    /// How many cells the instruction takes up, including the opcode
+    pub(crate) fn len(&self) -> usize {
+        match self {
+            Instruction::Add(..)
+            | Instruction::Mult(..)
+            | Instruction::LessThan(..)
+            | Instruction::Equals(..) => 4,
+            Instruction::JumpIfTrue(..) | Instruction::JumpIfFalse(..) => 3,
+            Instruction::Input(..)
+            | Instruction::Output(..)
+            | Instruction::AdjustRelativeBase(..) => 2,
+        }
+    }
+
+    /// Every parameter in order, paired with whether it is written to
+    fn parameters(&self) -> Vec<(Parameter, bool)> {
+        match *self {
+            Instruction::Add(a, b, c)
+            | Instruction::Mult(a, b, c)
+            | Instruction::LessThan(a, b, c)
+            | Instruction::Equals(a, b, c) => vec![(a, false), (b, false), (c, true)],
+            Instruction::Input(a) => vec![(a, true)],
+            Instruction::Output(a) | Instruction::AdjustRelativeBase(a) => vec![(a, false)],
+            Instruction::JumpIfTrue(a, b) | Instruction::JumpIfFalse(a, b) => {
+                vec![(a, false), (b, false)]
+            }
+        }
+    }
+}
+
+/// Why the computer stopped running
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunState {
+    /// Hit an input instruction with nothing queued, push an input and carry on
+    NeedsInput,
+    /// Produced a value
+    Output(i64),
+    /// Hit 99, the computer will stay halted
+    Halted,
+}
+
+/// A tape doing something the computer can't carry out. Every error has the address of the
+/// instruction that caused it, and the raw opcode found there where it makes sense
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComputerError {
+    /// The last two digits of the opcode aren't a known instruction
+    InvalidOpcode { address: usize, opcode: i64 },
+    /// A parameter mode digit isn't 0, 1 or 2
+    InvalidMode {
+        address: usize,
+        opcode: i64,
+        mode: i64,
+    },
+    /// A parameter that gets written to is in immediate mode
+    WriteToImmediate { address: usize, opcode: i64 },
+    /// A position, relative address or jump target below zero
+    NegativeAddress {
+        address: usize,
+        opcode: i64,
+        target: i64,
+    },
+    /// Needed an input but none will ever come
+    InputClosed { address: usize },
+    /// Produced an output but nothing is listening
+    OutputClosed { address: usize },
+}
+
+impl fmt::Display for ComputerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ComputerError::InvalidOpcode { address, opcode } => {
+                write!(f, "invalid opcode {opcode} at address {address}")
+            }
+            ComputerError::InvalidMode {
+                address,
+                opcode,
+                mode,
+            } => write!(
+                f,
+                "invalid parameter mode {mode} in opcode {opcode} at address {address}"
+            ),
+            ComputerError::WriteToImmediate { address, opcode } => write!(
+                f,
+                "write to immediate parameter in opcode {opcode} at address {address}"
+            ),
+            ComputerError::NegativeAddress {
+                address,
+                opcode,
+                target,
+            } => write!(
+                f,
+                "negative address {target} from opcode {opcode} at address {address}"
+            ),
+            ComputerError::InputClosed { address } => {
+                write!(f, "input closed while waiting at address {address}")
+            }
+            ComputerError::OutputClosed { address } => {
+                write!(f, "output closed while sending at address {address}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ComputerError {}
+
+/// An Intcode machine driven on the caller's thread, inputs are queued with `push_input` and
+/// outputs come back from `run_until_io`. Cloning forks the machine, both carry on independently
+#[derive(Debug, Clone)]
 pub struct Computer {
     mem: Memory,
     instruction_ptr: usize,
-    receiver: Receiver<i64>,
-    sender: Sender<i64>,
     relative_base: i64,
+    inputs: VecDeque<i64>,
+    cache: Option<OpCache>,
 }
 
 impl Computer {
-    pub fn load(tape: &[i64], receiver: Receiver<i64>, sender: Sender<i64>) -> Self {
-        let mut mem = vec![0; 10_000];
-        mem[0..tape.len()].clone_from_slice(tape);
-
+    pub fn new(tape: &[i64]) -> Self {
         Self {
-            mem: mem.try_into().expect("memory too big"),
+            mem: Memory::from_tape(tape),
             instruction_ptr: 0,
+            relative_base: 0,
+            inputs: VecDeque::new(),
+            cache: None,
+        }
+    }
+
+    /// Run with instructions decoded once per address into a compact form, several times faster
+    /// for tapes that loop. Writes over a decoded instruction throw it away, so self modifying
+    /// tapes still run correctly
+    pub fn with_decode_cache(mut self) -> Self {
+        self.cache = Some(OpCache::default());
+        self
+    }
+
+    /// Everything about the computer right now, restore it to carry on from here
+    pub fn snapshot(&self) -> ComputerState {
+        ComputerState {
+            instruction_ptr: self.instruction_ptr,
+            relative_base: self.relative_base,
+            inputs: self.inputs.iter().copied().collect(),
+            memory: self.mem.touched().to_vec(),
+            far_pages: self
+                .mem
+                .far_pages()
+                .map(|(start, values)| (start, values.to_vec()))
+                .collect(),
+        }
+    }
+
+    /// Rewind or jump the computer to a snapshot
+    pub fn restore(&mut self, state: &ComputerState) {
+        let cached = self.cache.is_some();
+        *self = Self::from(state);
+        if cached {
+            self.cache = Some(OpCache::default());
+        }
+    }
+
+    /// Load a tape wired up to channels, for driving the computer from another thread
+    pub fn load(tape: &[i64], receiver: Receiver<i64>, sender: Sender<i64>) -> ChannelComputer {
+        ChannelComputer {
+            computer: Self::new(tape),
             receiver,
             sender,
-            relative_base: 0,
         }
     }
 
-    /// Dumps the current state of the tape
-    pub fn dump_tape(&self) -> Memory {
-        self.mem.to_owned()
+    /// Dumps the tape from 0 up to the highest address written, far paged addresses aren't
+    /// included
+    pub fn dump_tape(&self) -> Vec<i64> {
+        self.mem.touched().to_vec()
     }
 
-    pub fn receiver(&self) -> &Receiver<i64> {
-        &self.receiver
+    pub fn memory(&self) -> &Memory {
+        &self.mem
     }
 
-    /// Runs the program until it halts
-    pub fn run(&mut self) {
-        while let Some(instruction) = self.next_instruction() {
-            let prev_instruction_ptr = self.instruction_ptr;
+    pub fn instruction_ptr(&self) -> usize {
+        self.instruction_ptr
+    }
 
-            match instruction {
-                Instruction::Add(a, b, out) => {
-                    let result = self.get_value(a) + self.get_value(b);
-                    self.mem[self.get_dest(out)] = result;
-                }
-                Instruction::Mult(a, b, out) => {
-                    let result = self.get_value(a) * self.get_value(b);
-                    self.mem[self.get_dest(out)] = result;
-                }
-                Instruction::Input(dest) => {
-                    let input = self.receiver.recv().expect("rec should never close");
-                    self.mem[self.get_dest(dest)] = input;
-                }
-                Instruction::Output(loc) => {
-                    let value = self.get_value(loc);
-                    self.sender.send(value).expect("send should never close");
+    pub fn set_instruction_ptr(&mut self, address: usize) {
+        self.instruction_ptr = address;
+    }
+
+    pub fn relative_base(&self) -> i64 {
+        self.relative_base
+    }
+
+    pub fn set_relative_base(&mut self, base: i64) {
+        self.relative_base = base;
+    }
+
+    /// Overwrite a memory cell, for patching a tape before or while it runs
+    pub fn write_memory(&mut self, address: usize, value: i64) {
+        self.write(address, value);
+    }
+
+    /// Inputs queued but not yet read
+    pub fn queued_inputs(&self) -> impl Iterator<Item = &i64> {
+        self.inputs.iter()
+    }
+
+    /// Queue a value for the next input instruction
+    pub fn push_input(&mut self, value: i64) {
+        self.inputs.push_back(value);
+    }
+
+    pub fn push_inputs(&mut self, values: impl IntoIterator<Item = i64>) {
+        self.inputs.extend(values);
+    }
+
+    /// Runs until the program needs an input that isn't queued, outputs a value or halts
+    pub fn run_until_io(&mut self) -> Result<RunState, ComputerError> {
+        loop {
+            if let Some(cache) = self.cache.as_mut() {
+                let registers = Registers {
+                    mem: &mut self.mem,
+                    instruction_ptr: &mut self.instruction_ptr,
+                    relative_base: &mut self.relative_base,
+                    inputs: &mut self.inputs,
+                };
+                if let Some(state) = compiled::run(cache, registers) {
+                    return Ok(state);
                 }
-                Instruction::JumpIfTrue(cond, loc) => {
-                    if self.get_value(cond) != 0 {
-                        self.instruction_ptr = self.get_value(loc) as usize;
-                    }
+            }
+
+            // Without a cache, or the compiled run stopped at something only step can report
+            if let Some(state) = self.step()? {
+                return Ok(state);
+            }
+        }
+    }
+
+    /// Like `run_until_io` but every instruction executed is handed to the tracer
+    pub fn run_until_io_traced(&mut self, tracer: &mut Tracer) -> Result<RunState, ComputerError> {
+        loop {
+            if let Some(state) = self.step_traced(tracer)? {
+                return Ok(state);
+            }
+        }
+    }
+
+    /// Runs until halted, collecting every output. Running out of queued inputs is treated as
+    /// the input being closed
+    pub fn run_to_halt(&mut self) -> Result<Vec<i64>, ComputerError> {
+        let mut outputs = Vec::new();
+        loop {
+            match self.run_until_io()? {
+                RunState::Output(value) => outputs.push(value),
+                RunState::Halted => return Ok(outputs),
+                RunState::NeedsInput => {
+                    return Err(ComputerError::InputClosed {
+                        address: self.instruction_ptr,
+                    })
                 }
-                Instruction::JumpIfFalse(cond, loc) => {
-                    if self.get_value(cond) == 0 {
-                        self.instruction_ptr = self.get_value(loc) as usize;
-                    }
+            }
+        }
+    }
+
+    /// Execute a single instruction. Returns None if the computer can carry on, an input
+    /// instruction with nothing queued is not executed so will be retried on the next step
+    pub fn step(&mut self) -> Result<Option<RunState>, ComputerError> {
+        let Some(instruction) = decode(&self.mem, self.instruction_ptr)? else {
+            return Ok(Some(RunState::Halted));
+        };
+
+        let prev_instruction_ptr = self.instruction_ptr;
+        let mut state = None;
+
+        match instruction {
+            Instruction::Add(a, b, out) => {
+                let result = self.get_value(a)? + self.get_value(b)?;
+                self.write(self.get_dest(out)?, result);
+            }
+            Instruction::Mult(a, b, out) => {
+                let result = self.get_value(a)? * self.get_value(b)?;
+                self.write(self.get_dest(out)?, result);
+            }
+            Instruction::Input(dest) => {
+                let dest = self.get_dest(dest)?;
+                let Some(input) = self.inputs.pop_front() else {
+                    return Ok(Some(RunState::NeedsInput));
+                };
+                self.write(dest, input);
+            }
+            Instruction::Output(loc) => {
+                state = Some(RunState::Output(self.get_value(loc)?));
+            }
+            Instruction::JumpIfTrue(cond, loc) => {
+                if self.get_value(cond)? != 0 {
+                    self.instruction_ptr = self.get_jump(loc)?;
                 }
-                Instruction::LessThan(a, b, loc) => {
-                    if self.get_value(a) < self.get_value(b) {
-                        self.mem[self.get_dest(loc)] = 1;
-                    } else {
-                        self.mem[self.get_dest(loc)] = 0;
-                    }
+            }
+            Instruction::JumpIfFalse(cond, loc) => {
+                if self.get_value(cond)? == 0 {
+                    self.instruction_ptr = self.get_jump(loc)?;
                 }
-                Instruction::Equals(a, b, loc) => {
-                    if self.get_value(a) == self.get_value(b) {
-                        self.mem[self.get_dest(loc)] = 1;
-                    } else {
-                        self.mem[self.get_dest(loc)] = 0;
-                    }
+            }
+            Instruction::LessThan(a, b, loc) => {
+                if self.get_value(a)? < self.get_value(b)? {
+                    self.write(self.get_dest(loc)?, 1);
+                } else {
+                    self.write(self.get_dest(loc)?, 0);
                 }
-                Instruction::AdjustRelativeBase(a) => {
-                    self.relative_base = self.relative_base + self.get_value(a);
+            }
+            Instruction::Equals(a, b, loc) => {
+                if self.get_value(a)? == self.get_value(b)? {
+                    self.write(self.get_dest(loc)?, 1);
+                } else {
+                    self.write(self.get_dest(loc)?, 0);
                 }
             }
-
-            if self.instruction_ptr == prev_instruction_ptr {
-                self.advance(&instruction);
+            Instruction::AdjustRelativeBase(a) => {
+                self.relative_base += self.get_value(a)?;
             }
         }
+
+        if self.instruction_ptr == prev_instruction_ptr {
+            self.advance(&instruction);
+        }
+
+        Ok(state)
     }
 
-    /// Parse the instruction at the current head, returns None for Halt
-    fn next_instruction(&self) -> Option<Instruction> {
-        let opcode = self.mem[self.instruction_ptr];
-        let instruction_code = parse_opcode(opcode);
-        match instruction_code {
-            99 => None, // Halt
-            1 => Some(Instruction::Add(
-                parse_parameter(opcode, 1, self.mem[self.instruction_ptr + 1]),
-                parse_parameter(opcode, 2, self.mem[self.instruction_ptr + 2]),
-                parse_parameter(opcode, 3, self.mem[self.instruction_ptr + 3]),
-            )),
-            2 => Some(Instruction::Mult(
-                parse_parameter(opcode, 1, self.mem[self.instruction_ptr + 1]),
-                parse_parameter(opcode, 2, self.mem[self.instruction_ptr + 2]),
-                parse_parameter(opcode, 3, self.mem[self.instruction_ptr + 3]),
-            )),
-            3 => Some(Instruction::Input(
-                parse_parameter(opcode, 1 ,self.mem[self.instruction_ptr + 1]),
-            )),
-            4 => Some(Instruction::Output(parse_parameter(
-                opcode,
-                1,
-                self.mem[self.instruction_ptr + 1],
-            ))),
-            5 => Some(Instruction::JumpIfTrue(
-                parse_parameter(opcode, 1, self.mem[self.instruction_ptr + 1]),
-                parse_parameter(opcode, 2, self.mem[self.instruction_ptr + 2]),
-            )),
-            6 => Some(Instruction::JumpIfFalse(
-                parse_parameter(opcode, 1, self.mem[self.instruction_ptr + 1]),
-                parse_parameter(opcode, 2, self.mem[self.instruction_ptr + 2]),
-            )),
-            7 => Some(Instruction::LessThan(
-                parse_parameter(opcode, 1, self.mem[self.instruction_ptr + 1]),
-                parse_parameter(opcode, 2, self.mem[self.instruction_ptr + 2]),
-                parse_parameter(opcode, 3, self.mem[self.instruction_ptr + 3]),
-            )),
-            8 => Some(Instruction::Equals(
-                parse_parameter(opcode, 1, self.mem[self.instruction_ptr + 1]),
-                parse_parameter(opcode, 2, self.mem[self.instruction_ptr + 2]),
-                parse_parameter(opcode, 3, self.mem[self.instruction_ptr + 3]),
-            )),
-            9 => Some(Instruction::AdjustRelativeBase(parse_parameter(
-                opcode,
-                1,
-                self.mem[self.instruction_ptr + 1],
-            ))),
-            _ => panic!("unexpected instruction code"),
+    fn write(&mut self, address: usize, value: i64) {
+        self.mem.write(address, value);
+        if let Some(cache) = self.cache.as_mut() {
+            cache.invalidate(address);
+        }
+    }
+
+    /// Like `step` but the instruction is handed to the tracer if it executes
+    pub fn step_traced(&mut self, tracer: &mut Tracer) -> Result<Option<RunState>, ComputerError> {
+        let address = self.instruction_ptr;
+        let opcode = self.opcode();
+        let Some(instruction) = decode(&self.mem, address)? else {
+            return self.step();
+        };
+
+        // Operands are resolved before executing, an instruction can overwrite its own operands
+        let mut operands = Vec::new();
+        let mut dests = Vec::new();
+        for (parameter, writes) in instruction.parameters() {
+            if writes {
+                let dest = self.get_dest(parameter)?;
+                operands.push(dest as i64);
+                dests.push(dest);
+            } else {
+                operands.push(self.get_value(parameter)?);
+            }
+        }
+
+        let state = self.step()?;
+        if state == Some(RunState::NeedsInput) {
+            return Ok(state);
         }
+
+        tracer.record(TraceRecord {
+            step: 0,
+            address,
+            opcode,
+            instruction: format_instruction(&instruction, &Default::default()),
+            operands,
+            writes: dests
+                .into_iter()
+                .map(|dest| (dest, self.mem.read(dest)))
+                .collect(),
+        });
+
+        Ok(state)
     }
 
-    fn get_value(&self, parameter: Parameter) -> i64 {
+    fn get_value(&self, parameter: Parameter) -> Result<i64, ComputerError> {
         match parameter {
-            Parameter::Position(index) => self.mem[index],
-            Parameter::Immediate(x) => x,
-            Parameter::Relative(offset) => self.mem[(self.relative_base + offset) as usize],
+            Parameter::Position(index) => Ok(self.mem.read(index)),
+            Parameter::Immediate(x) => Ok(x),
+            Parameter::Relative(offset) => Ok(self.mem.read(self.relative_address(offset)?)),
         }
     }
 
     /// For use by instructions with a write parameter
-    fn get_dest(&self, parameter: Parameter) -> usize {
+    fn get_dest(&self, parameter: Parameter) -> Result<usize, ComputerError> {
         match parameter {
-            Parameter::Position(index) => index,
-            Parameter::Immediate(_) => panic!("immediate params not valid for dest params"),
-            Parameter::Relative(offset) => (self.relative_base + offset) as usize,
+            Parameter::Position(index) => Ok(index),
+            Parameter::Immediate(_) => Err(ComputerError::WriteToImmediate {
+                address: self.instruction_ptr,
+                opcode: self.opcode(),
+            }),
+            Parameter::Relative(offset) => self.relative_address(offset),
         }
     }
 
+    /// For use by jump instructions, where the value is the address to jump to
+    fn get_jump(&self, parameter: Parameter) -> Result<usize, ComputerError> {
+        let target = self.get_value(parameter)?;
+        self.check_address(target)
+    }
+
+    fn relative_address(&self, offset: i64) -> Result<usize, ComputerError> {
+        self.check_address(self.relative_base + offset)
+    }
+
+    fn check_address(&self, target: i64) -> Result<usize, ComputerError> {
+        usize::try_from(target).map_err(|_| ComputerError::NegativeAddress {
+            address: self.instruction_ptr,
+            opcode: self.opcode(),
+            target,
+        })
+    }
+
+    fn opcode(&self) -> i64 {
+        self.mem.read(self.instruction_ptr)
+    }
+
     /// Advance to the next instruction
     fn advance(&mut self, instruction: &Instruction) {
-        let to_advance = match instruction {
-            Instruction::Add(..)
-            | Instruction::Mult(..)
-            | Instruction::LessThan(..)
-            | Instruction::Equals(..) => 4,
-            Instruction::JumpIfTrue(..) | Instruction::JumpIfFalse(..) => 3,
-            Instruction::Input(..)
-            | Instruction::Output(..)
-            | Instruction::AdjustRelativeBase(..) => 2,
-        };
+        self.instruction_ptr += instruction.len();
+    }
+}
+
+impl From<&ComputerState> for Computer {
+    fn from(state: &ComputerState) -> Self {
+        let far_pages = state
+            .far_pages
+            .iter()
+            .map(|(start, values)| (*start, values.clone()));
+
+        Self {
+            mem: Memory::from_parts(state.memory.clone(), far_pages),
+            instruction_ptr: state.instruction_ptr,
+            relative_base: state.relative_base,
+            inputs: state.inputs.iter().copied().collect(),
+            cache: None,
+        }
+    }
+}
+
+/// A computer whose inputs are received from and outputs sent down channels, so it can run on its
+/// own thread
+pub struct ChannelComputer {
+    computer: Computer,
+    receiver: Receiver<i64>,
+    sender: Sender<i64>,
+}
+
+impl ChannelComputer {
+    /// Runs the program until it halts, blocking whenever it needs an input
+    pub fn run(&mut self) -> Result<(), ComputerError> {
+        self.drive(Computer::run_until_io)
+    }
+
+    /// Like `run` but every instruction executed is handed to the tracer
+    pub fn run_traced(&mut self, tracer: &mut Tracer) -> Result<(), ComputerError> {
+        self.drive(|computer| computer.run_until_io_traced(tracer))
+    }
 
-        self.instruction_ptr += to_advance;
+    /// Pass inputs and outputs over the channels until run_until_io says the computer halted
+    fn drive(
+        &mut self,
+        mut run_until_io: impl FnMut(&mut Computer) -> Result<RunState, ComputerError>,
+    ) -> Result<(), ComputerError> {
+        loop {
+            match run_until_io(&mut self.computer)? {
+                RunState::NeedsInput => {
+                    let input = self
+                        .receiver
+                        .recv()
+                        .map_err(|_| ComputerError::InputClosed {
+                            address: self.computer.instruction_ptr,
+                        })?;
+                    self.computer.push_input(input);
+                }
+                RunState::Output(value) => {
+                    self.sender
+                        .send(value)
+                        .map_err(|_| ComputerError::OutputClosed {
+                            address: self.computer.instruction_ptr,
+                        })?;
+                }
+                RunState::Halted => return Ok(()),
+            }
+        }
+    }
+
+    pub fn receiver(&self) -> &Receiver<i64> {
+        &self.receiver
+    }
+
+    pub fn dump_tape(&self) -> Vec<i64> {
+        self.computer.dump_tape()
+    }
+
+    pub fn memory(&self) -> &Memory {
+        self.computer.memory()
+    }
+
+    /// Unhook the channels and drive the computer directly
+    pub fn into_computer(self) -> Computer {
+        self.computer
     }
 }
 
 /// Expects comma separated list of numbers
 pub fn parse_tape(input: &str) -> Vec<i64> {
+    try_parse_tape(input).expect("only numbers in input")
+}
+
+/// Why some text isn't a tape
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseTapeError {
+    Empty,
+    /// The value at index (counting from 0) isn't a number
+    BadValue {
+        index: usize,
+        value: String,
+    },
+}
+
+impl fmt::Display for ParseTapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseTapeError::Empty => write!(f, "tape is empty"),
+            ParseTapeError::BadValue { index, value } => {
+                write!(f, "value {index} of the tape isn't a number: {value:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseTapeError {}
+
+/// Like `parse_tape` but with an error for anything that isn't a comma separated list of
+/// numbers
+pub fn try_parse_tape(input: &str) -> Result<Vec<i64>, ParseTapeError> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(ParseTapeError::Empty);
+    }
+
     input
-        .trim()
         .split(',')
-        .map(|x| x.parse().expect("only numbers in input"))
+        .enumerate()
+        .map(|(index, value)| {
+            value.trim().parse().map_err(|_| ParseTapeError::BadValue {
+                index,
+                value: value.to_string(),
+            })
+        })
         .collect()
 }
 
-fn parse_opcode(value: i64) -> usize {
-    (value % 100) as usize
+/// Parse the instruction at address, returns None for Halt
+pub(crate) fn decode(mem: &Memory, address: usize) -> Result<Option<Instruction>, ComputerError> {
+    let opcode = mem.read(address);
+    let param = |param_pos: usize| {
+        parse_parameter(opcode, param_pos, mem.read(address + param_pos)).map_err(|err| match err {
+            ParameterError::InvalidMode(mode) => ComputerError::InvalidMode {
+                address,
+                opcode,
+                mode,
+            },
+            ParameterError::NegativePosition(target) => ComputerError::NegativeAddress {
+                address,
+                opcode,
+                target,
+            },
+        })
+    };
+
+    let instruction = match parse_opcode(opcode) {
+        99 => return Ok(None), // Halt
+        1 => Instruction::Add(param(1)?, param(2)?, param(3)?),
+        2 => Instruction::Mult(param(1)?, param(2)?, param(3)?),
+        3 => Instruction::Input(param(1)?),
+        4 => Instruction::Output(param(1)?),
+        5 => Instruction::JumpIfTrue(param(1)?, param(2)?),
+        6 => Instruction::JumpIfFalse(param(1)?, param(2)?),
+        7 => Instruction::LessThan(param(1)?, param(2)?, param(3)?),
+        8 => Instruction::Equals(param(1)?, param(2)?, param(3)?),
+        9 => Instruction::AdjustRelativeBase(param(1)?),
+        _ => return Err(ComputerError::InvalidOpcode { address, opcode }),
+    };
+
+    Ok(Some(instruction))
+}
+
+fn parse_opcode(value: i64) -> i64 {
+    value % 100
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum ParameterError {
+    InvalidMode(i64),
+    NegativePosition(i64),
 }
 
 /// Get the parameter at position [1 based]
-fn parse_parameter(opcode: i64, param_pos: usize, value: i64) -> Parameter {
+fn parse_parameter(opcode: i64, param_pos: usize, value: i64) -> Result<Parameter, ParameterError> {
     let flag = (opcode / 10i64.pow(param_pos as u32 + 1)) % 10;
     match flag {
-        0 => Parameter::Position(value as usize),
-        1 => Parameter::Immediate(value),
-        2 => Parameter::Relative(value),
-        _ => panic!("unknown parameter mode {}", flag),
+        0 => usize::try_from(value)
+            .map(Parameter::Position)
+            .map_err(|_| ParameterError::NegativePosition(value)),
+        1 => Ok(Parameter::Immediate(value)),
+        2 => Ok(Parameter::Relative(value)),
+        _ => Err(ParameterError::InvalidMode(flag)),
     }
 }
 
@@ -243,15 +667,15 @@ mod tests {
 
         assert_eq!(
             parse_parameter(opcode, 1, value),
-            Parameter::Position(value as usize)
+            Ok(Parameter::Position(value as usize))
         );
         assert_eq!(
             parse_parameter(opcode, 2, value),
-            Parameter::Immediate(value)
+            Ok(Parameter::Immediate(value))
         );
         assert_eq!(
             parse_parameter(opcode, 3, value),
-            Parameter::Position(value as usize)
+            Ok(Parameter::Position(value as usize))
         );
     }
 
@@ -262,11 +686,36 @@ mod tests {
 
         assert_eq!(
             parse_parameter(opcode, 1, value),
-            Parameter::Position(value as usize)
+            Ok(Parameter::Position(value as usize))
         );
         assert_eq!(
             parse_parameter(opcode, 2, value),
-            Parameter::Relative(value)
+            Ok(Parameter::Relative(value))
+        );
+    }
+
+    #[test]
+    fn parse_bad_parameters() {
+        assert_eq!(
+            parse_parameter(302, 1, 5),
+            Err(ParameterError::InvalidMode(3))
+        );
+        assert_eq!(
+            parse_parameter(2, 2, -1),
+            Err(ParameterError::NegativePosition(-1))
+        );
+    }
+
+    #[test]
+    fn try_parse_tape_errors() {
+        assert_eq!(try_parse_tape("1,-2, 3\n"), Ok(vec![1, -2, 3]));
+        assert_eq!(try_parse_tape(" \n"), Err(ParseTapeError::Empty));
+        assert_eq!(
+            try_parse_tape("1,2,x,4"),
+            Err(ParseTapeError::BadValue {
+                index: 2,
+                value: "x".to_string()
+            })
         );
     }
 }
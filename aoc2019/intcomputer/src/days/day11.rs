@@ -1,10 +1,59 @@
 use std::{
     collections::{HashMap, HashSet},
-    sync::mpsc::{self, Receiver, Sender},
-    thread,
+    error::Error,
+    fmt,
 };
 
-use crate::{computer::{parse_tape, Computer}, Point};
+use aoc_solution::{puzzle, Solution};
+
+use crate::{
+    computer::{parse_tape, Computer, ComputerError, RunState},
+    Point,
+};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    fn part1(&self, input: &str) -> String {
+        part1(input)
+            .expect("tape should run without error")
+            .to_string()
+    }
+
+    fn part2(&self, input: &str) -> Option<String> {
+        Some(part2(input).expect("tape should run without error"))
+    }
+}
+
+puzzle!(2019, 11, Day11, "src/inputs/day11_input.txt");
+
+/// Why the robot had to stop painting
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RobotError {
+    Computer(ComputerError),
+    /// Colours and turns are both 0 or 1
+    UnexpectedOutput(i64),
+    /// The tape halted or asked for input between a colour and its turn
+    MissingTurn,
+}
+
+impl fmt::Display for RobotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RobotError::Computer(error) => write!(f, "{error}"),
+            RobotError::UnexpectedOutput(output) => write!(f, "unexpected robot output {output}"),
+            RobotError::MissingTurn => write!(f, "robot painted without saying which way to turn"),
+        }
+    }
+}
+
+impl Error for RobotError {}
+
+impl From<ComputerError> for RobotError {
+    fn from(error: ComputerError) -> Self {
+        RobotError::Computer(error)
+    }
+}
 
 #[derive(Debug)]
 enum Colour {
@@ -19,50 +68,36 @@ enum Direction {
     Right,
 }
 
-pub fn part1(input: &str) -> usize {
+pub fn part1(input: &str) -> Result<usize, RobotError> {
     let tape = parse_tape(input);
-    let (computer_sender, computer_receiver) = mpsc::channel();
-    let (robot_sender, robot_receiver) = mpsc::channel();
 
     let mut grid: HashMap<Point, Colour> = HashMap::new();
-    let mut computer = Computer::load(&tape, computer_receiver, robot_sender);
+    let mut computer = Computer::new(&tape);
 
-    // Hoping this will close the robot's sender and so it will know to stop
-    thread::spawn(move || {
-        computer.run();
-        drop(computer);
-    });
+    robot(&mut computer, &mut grid)?;
 
-    robot(computer_sender, robot_receiver, &mut grid);
+    let visited: HashSet<Point> = grid.keys().copied().collect();
 
-    let visited: HashSet<Point> = grid.keys().map(|p| *p).collect();
-
-    visited.len()
+    Ok(visited.len())
 }
 
-pub fn part2(input: &str) {
+/// The registration the robot paints, as rows of #
+pub fn part2(input: &str) -> Result<String, RobotError> {
     let tape = parse_tape(input);
-    let (computer_sender, computer_receiver) = mpsc::channel();
-    let (robot_sender, robot_receiver) = mpsc::channel();
 
     let mut grid: HashMap<Point, Colour> = HashMap::new();
-    let mut computer = Computer::load(&tape, computer_receiver, robot_sender);
+    let mut computer = Computer::new(&tape);
 
     grid.insert(Point::new(0, 0), Colour::White);
 
-    // Hoping this will close the robot's sender and so it will know to stop
-    thread::spawn(move || {
-        computer.run();
-        drop(computer);
-    });
-
-    robot(computer_sender, robot_receiver, &mut grid);
+    robot(&mut computer, &mut grid)?;
 
     let min_x = grid.keys().min_by(|a, b| a.x.cmp(&b.x)).expect("should be a point").x;
     let max_x = grid.keys().max_by(|a, b| a.x.cmp(&b.x)).expect("should be a point").x;
     let min_y = grid.keys().min_by(|a, b| a.y.cmp(&b.y)).expect("should be a point").y;
     let max_y = grid.keys().max_by(|a, b| a.y.cmp(&b.y)).expect("should be a point").y;
 
+    let mut registration = String::new();
     for y in (min_y..=max_y).rev() {
         let mut row = String::new();
         for x in min_x..=max_x {
@@ -73,45 +108,46 @@ pub fn part2(input: &str) {
             };
             row.push(cell);
         }
-        println!("{row}");
+        registration.push_str(&row);
+        registration.push('\n');
     }
+
+    Ok(registration)
 }
 
-fn robot(sender: Sender<i64>, receiver: Receiver<i64>, grid: &mut HashMap<Point, Colour>) {
+/// The computer asks for the colour under the robot, then outputs a colour to paint followed by
+/// which way to turn before moving forwards
+fn robot(
+    computer: &mut Computer,
+    grid: &mut HashMap<Point, Colour>,
+) -> Result<(), RobotError> {
     let mut position = Point::new(0, 0);
     let mut facing = Direction::Up;
-    let mut painting = true;
-
-    sender
-        .send(encode_colour(&position, &grid))
-        .expect("computer should not close receiver");
-
-    while let Ok(instruction) = receiver.recv() {
-        match painting {
-            true => {
-                let colour = match instruction {
-                    0 => Colour::Black,
-                    1 => Colour::White,
-                    _ => panic!("unexpected instruction from computer"),
-                };
-                grid.insert(position, colour);
-                painting = false;
-            }
-            false => {
-                // 0 = anti-clockwise, 1 = clockwise
-                facing = match instruction {
-                    0 => rotate(&facing, false),
-                    1 => rotate(&facing, true),
-                    _ => panic!("unexpected instruction from computer"),
-                };
-
-                position = forwards(&position, &facing);
-
-                // Computer may have halted and closed its receiver but we don't care
-                let _ = sender.send(encode_colour(&position, &grid));
-                painting = true;
+
+    loop {
+        let state = computer.run_until_io()?;
+        let colour = match state {
+            RunState::NeedsInput => {
+                computer.push_input(encode_colour(&position, grid));
+                continue;
             }
-        }
+            RunState::Output(0) => Colour::Black,
+            RunState::Output(1) => Colour::White,
+            RunState::Output(other) => return Err(RobotError::UnexpectedOutput(other)),
+            RunState::Halted => return Ok(()),
+        };
+        grid.insert(position, colour);
+
+        // 0 = anti-clockwise, 1 = clockwise
+        let state = computer.run_until_io()?;
+        facing = match state {
+            RunState::Output(0) => rotate(&facing, false),
+            RunState::Output(1) => rotate(&facing, true),
+            RunState::Output(other) => return Err(RobotError::UnexpectedOutput(other)),
+            RunState::NeedsInput | RunState::Halted => return Err(RobotError::MissingTurn),
+        };
+
+        position = forwards(&position, &facing);
     }
 }
 
@@ -1,16 +1,76 @@
 use std::{
     collections::HashMap,
-    sync::mpsc::{self, Receiver, Sender},
-    thread,
+    error::Error,
+    fmt,
+    io::{self, Write},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use aoc_solution::{puzzle, Solution};
+use crossterm::{
+    cursor,
+    event::{self, Event as TerminalEvent, KeyCode, KeyEventKind, KeyModifiers},
+    execute, queue,
+    style::Print,
+    terminal::{self, ClearType},
 };
 
 use crate::{
-    computer::{parse_tape, Computer},
+    computer::{parse_tape, Computer, ComputerError, RunState},
     Point,
 };
 
-#[derive(Clone, Copy)]
-enum Tile {
+pub struct Day13;
+
+impl Solution for Day13 {
+    fn part1(&self, input: &str) -> String {
+        part1(input)
+            .expect("tape should run without error")
+            .to_string()
+    }
+
+    fn part2(&self, input: &str) -> Option<String> {
+        Some(
+            part2(input)
+                .expect("tape should run without error")
+                .to_string(),
+        )
+    }
+}
+
+puzzle!(2019, 13, Day13, "src/inputs/day13_input.txt");
+
+/// Why a game couldn't go on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArcadeError {
+    Computer(ComputerError),
+    /// The tile id in a draw instruction isn't one the cabinet knows
+    UnknownTile(i64),
+    /// Scores can only count up from zero
+    NegativeScore(i64),
+}
+
+impl fmt::Display for ArcadeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArcadeError::Computer(error) => write!(f, "{error}"),
+            ArcadeError::UnknownTile(tile) => write!(f, "unknown tile instruction {tile}"),
+            ArcadeError::NegativeScore(score) => write!(f, "negative score {score}"),
+        }
+    }
+}
+
+impl Error for ArcadeError {}
+
+impl From<ComputerError> for ArcadeError {
+    fn from(error: ComputerError) -> Self {
+        ArcadeError::Computer(error)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tile {
     Empty,
     Wall,
     Block,
@@ -18,145 +78,379 @@ enum Tile {
     Ball,
 }
 
+impl Tile {
+    fn symbol(self) -> char {
+        match self {
+            Tile::Empty => ' ',
+            Tile::Wall => '#',
+            Tile::Block => '=',
+            Tile::HorizontalPaddle => '-',
+            Tile::Ball => 'o',
+        }
+    }
+}
+
 enum Event {
     TileUpdate(Point, Tile),
     ScoreUpdate(usize),
 }
 
-pub fn part1(input: &str) -> usize {
-    let tape = parse_tape(input);
-    let (_computer_sender, computer_receiver) = mpsc::channel();
-    let (arcade_sender, arcade_receiver) = mpsc::channel();
-
-    let mut grid: HashMap<Point, Tile> = HashMap::new();
-    let mut computer = Computer::load(&tape, computer_receiver, arcade_sender);
-
-    thread::spawn(move || {
-        computer.run();
-        drop(computer);
-    });
+/// Which way the joystick is held when the game asks for input
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Joystick {
+    Left,
+    Neutral,
+    Right,
+}
 
-    arcade(arcade_receiver, &mut grid);
+impl Joystick {
+    fn input(self) -> i64 {
+        match self {
+            Joystick::Left => -1,
+            Joystick::Neutral => 0,
+            Joystick::Right => 1,
+        }
+    }
 
-    grid.values().filter(|t| matches!(*t, &Tile::Block)).count()
+    fn symbol(self) -> char {
+        match self {
+            Joystick::Left => '<',
+            Joystick::Neutral => '.',
+            Joystick::Right => '>',
+        }
+    }
 }
 
-pub fn part2(input: &str) -> usize {
-    let mut tape = parse_tape(input);
-    tape[0] = 2; // Put quarter in to play
+/// Every joystick move made in a game, written as a line of `<`, `.` and `>` so a game can be
+/// saved and replayed
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Moves(pub Vec<Joystick>);
 
-    let (computer_sender, computer_receiver) = mpsc::channel();
-    let (output_processor_sender, output_processor_receiver) = mpsc::channel();
-    let (event_sender, event_receiver) = mpsc::channel();
+impl fmt::Display for Moves {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for joystick in self.0.iter() {
+            write!(f, "{}", joystick.symbol())?;
+        }
+        Ok(())
+    }
+}
 
-    let mut computer = Computer::load(&tape, computer_receiver, output_processor_sender);
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseMovesError(pub char);
 
-    let mut grid: HashMap<Point, Tile> = HashMap::new();
-    let mut score = 0;
+impl fmt::Display for ParseMovesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown move {:?}, expected <, . or >", self.0)
+    }
+}
 
-    thread::spawn(move || {
-        computer.run();
-        drop(computer);
-    });
+impl Error for ParseMovesError {}
 
-    thread::spawn(move || {
-        output_processor(event_sender, output_processor_receiver);
-    });
+impl FromStr for Moves {
+    type Err = ParseMovesError;
 
-    // From debugging worked out that the computer expects input immediately
-    computer_sender.send(0).unwrap();
+    /// Whitespace is skipped so recordings can be wrapped
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| match c {
+                '<' => Ok(Joystick::Left),
+                '.' => Ok(Joystick::Neutral),
+                '>' => Ok(Joystick::Right),
+                c => Err(ParseMovesError(c)),
+            })
+            .collect::<Result<_, _>>()
+            .map(Moves)
+    }
+}
 
-    loop {
-        let Ok(event) = event_receiver.recv() else {
-            break;
-        };
+/// What the cabinet is showing
+#[derive(Debug, Clone, Default)]
+pub struct Screen {
+    tiles: HashMap<Point, Tile>,
+    pub score: usize,
+}
 
+impl Screen {
+    fn update(&mut self, event: Event) {
         match event {
-            Event::ScoreUpdate(new_score) => {
-                score = new_score;
-            }
             Event::TileUpdate(point, tile) => {
-                grid.insert(point, tile);
-
-                let Some(ball_pos) = grid
-                    .iter()
-                    .find(|(_k, v)| matches!(v, &&Tile::Ball))
-                    .map(|(point, _tile)| point)
-                else {
-                    continue; // ball not loaded yet or game finished
-                };
-                let Some(paddle_pos) = grid
-                    .iter()
-                    .find(|(_k, v)| matches!(v, &&Tile::HorizontalPaddle))
-                    .map(|(point, _tile)| point)
-                else {
-                    continue; // paddle not loaded yet or game finished
-                };
-
-                // Only perform input on ball update to avoid filling up queue with same input
-                if !matches!(tile, Tile::Ball) {
-                    continue;
-                }
+                self.tiles.insert(point, tile);
+            }
+            Event::ScoreUpdate(score) => self.score = score,
+        }
+    }
 
-                let joystick_input = match ball_pos.x.cmp(&paddle_pos.x) {
-                    std::cmp::Ordering::Less => -1,
-                    std::cmp::Ordering::Equal => 0,
-                    std::cmp::Ordering::Greater => 1,
-                };
+    pub fn tile(&self, point: Point) -> Tile {
+        self.tiles.get(&point).copied().unwrap_or(Tile::Empty)
+    }
 
-                let _ = computer_sender.send(joystick_input); // Computer may have ended by this point but we don't care
-            }
+    pub fn blocks(&self) -> usize {
+        self.tiles
+            .values()
+            .filter(|t| matches!(t, Tile::Block))
+            .count()
+    }
+
+    /// Will return none before the ball is drawn
+    pub fn ball(&self) -> Option<Point> {
+        self.find(Tile::Ball)
+    }
+
+    /// Will return none before the paddle is drawn
+    pub fn paddle(&self) -> Option<Point> {
+        self.find(Tile::HorizontalPaddle)
+    }
+
+    fn find(&self, tile: Tile) -> Option<Point> {
+        self.tiles
+            .iter()
+            .find(|(_point, t)| **t == tile)
+            .map(|(point, _tile)| *point)
+    }
+}
+
+impl fmt::Display for Screen {
+    /// Rows of tiles from the top left followed by the score
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let max_x = self.tiles.keys().map(|p| p.x).max().unwrap_or(-1);
+        let max_y = self.tiles.keys().map(|p| p.y).max().unwrap_or(-1);
+
+        for y in 0..=max_y {
+            let row: String = (0..=max_x)
+                .map(|x| self.tile(Point::new(x, y)).symbol())
+                .collect();
+            writeln!(f, "{row}")?;
         }
+
+        write!(f, "Score: {}", self.score)
     }
+}
 
-    score
+/// How a game went
+#[derive(Debug, Clone)]
+pub struct Game {
+    pub screen: Screen,
+    pub moves: Moves,
+    /// False if the joystick gave up before the tape halted
+    pub finished: bool,
 }
 
-fn output_processor(event_sender: Sender<Event>, processor_receiver: Receiver<i64>) {
-    loop {
-        let Ok(x) = processor_receiver.recv() else {
-            break;
-        };
-        let y = processor_receiver
-            .recv()
-            .expect("computer closed mid instruction");
-        let z = processor_receiver
-            .recv()
-            .expect("computer closed mid instruction");
-
-        let event = match (x, y, z) {
-            (-1, 0, score) => Event::ScoreUpdate(score as usize),
-            (x, y, z) => Event::TileUpdate(Point::new(x, y), parse_tile(z)),
-        };
+pub fn part1(input: &str) -> Result<usize, ArcadeError> {
+    let tape = parse_tape(input);
+    let mut computer = Computer::new(&tape);
+    let mut screen = Screen::default();
+
+    // Without a quarter the tape just draws the screen and never asks for input
+    arcade(&mut computer, &mut screen, |_screen| None)?;
+
+    Ok(screen.blocks())
+}
+
+pub fn part2(input: &str) -> Result<usize, ArcadeError> {
+    let tape = free_play(&parse_tape(input));
+
+    let game = play(&tape, |screen| Some(bot(screen)))?;
+
+    Ok(game.screen.score)
+}
 
-        event_sender
-            .send(event)
-            .expect("game loop should not close before event processor");
+/// Put quarter in to play
+pub fn free_play(tape: &[i64]) -> Vec<i64> {
+    let mut tape = tape.to_vec();
+    tape[0] = 2;
+    tape
+}
+
+/// Keeps the paddle under the ball so it never misses
+pub fn bot(screen: &Screen) -> Joystick {
+    let (Some(ball), Some(paddle)) = (screen.ball(), screen.paddle()) else {
+        return Joystick::Neutral; // ball or paddle not drawn yet
+    };
+
+    match ball.x.cmp(&paddle.x) {
+        std::cmp::Ordering::Less => Joystick::Left,
+        std::cmp::Ordering::Equal => Joystick::Neutral,
+        std::cmp::Ordering::Greater => Joystick::Right,
     }
 }
 
-fn arcade(receiver: Receiver<i64>, grid: &mut HashMap<Point, Tile>) {
-    loop {
-        let Ok(x_instruct) = receiver.recv() else {
-            break;
+/// Run a tape with joystick asked for a move each time it wants input, until it halts or the
+/// joystick gives none. Every move made is kept so the game can be replayed
+pub fn play(
+    tape: &[i64],
+    mut joystick: impl FnMut(&Screen) -> Option<Joystick>,
+) -> Result<Game, ArcadeError> {
+    let mut computer = Computer::new(tape).with_decode_cache();
+    let mut screen = Screen::default();
+    let mut moves = Moves::default();
+
+    let finished = arcade(&mut computer, &mut screen, |screen| {
+        let joystick = joystick(screen)?;
+        moves.0.push(joystick);
+        Some(joystick)
+    })?;
+
+    Ok(Game {
+        screen,
+        moves,
+        finished,
+    })
+}
+
+/// Play in the terminal with the arrow keys, a frame passes every frame time whether a key is
+/// pressed or not. Moves from replay are made first, one a frame, before the keyboard takes over
+pub fn play_in_terminal(tape: &[i64], replay: &Moves, frame_time: Duration) -> io::Result<Game> {
+    let _terminal = GameTerminal::enter()?;
+    let mut stdout = io::stdout();
+
+    let mut replay = replay.0.iter().copied().enumerate();
+    let replay_len = replay.len();
+    let mut terminal_error = None;
+    let game = play(tape, |screen| {
+        let next = match replay.next() {
+            Some((i, joystick)) => {
+                let status = format!("replaying move {}/{replay_len}, q to quit", i + 1);
+                // Keys other than quit are ignored until the replay runs out
+                draw(&mut stdout, screen, &status)
+                    .and_then(|_| read_joystick(frame_time))
+                    .map(|read| read.map(|_| joystick))
+            }
+            None => draw(&mut stdout, screen, "<- -> to move, q to quit")
+                .and_then(|_| read_joystick(frame_time)),
         };
-        let y_instruct = receiver.recv().expect("computer shouldn't close mid cycle");
-        let tile_instruct = receiver.recv().expect("computer shouldn't close mid cycle");
 
-        let position = Point::new(x_instruct, y_instruct);
-        let tile = parse_tile(tile_instruct);
+        next.unwrap_or_else(|err| {
+            terminal_error = Some(err);
+            None
+        })
+    });
+
+    if let Some(err) = terminal_error {
+        return Err(err);
+    }
+    game.map_err(io::Error::other)
+}
+
+/// Raw mode on the alternate screen, put back when dropped so the terminal is left usable
+/// however the game ends
+struct GameTerminal;
+
+impl GameTerminal {
+    fn enter() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        // Made before the screen is switched so raw mode is undone if that fails
+        let terminal = GameTerminal;
+        execute!(
+            io::stdout(),
+            terminal::EnterAlternateScreen,
+            terminal::Clear(ClearType::All),
+            cursor::Hide
+        )?;
+        Ok(terminal)
+    }
+}
+
+impl Drop for GameTerminal {
+    fn drop(&mut self) {
+        // Nothing more can be done if these fail
+        let _ = execute!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Runs the computer, turning each three outputs into a screen update and asking joystick for
+/// input. Returns whether the tape halted, false if the joystick gave up first
+fn arcade(
+    computer: &mut Computer,
+    screen: &mut Screen,
+    mut joystick: impl FnMut(&Screen) -> Option<Joystick>,
+) -> Result<bool, ArcadeError> {
+    let mut outputs = Vec::with_capacity(3);
+
+    loop {
+        match computer.run_until_io()? {
+            RunState::Output(value) => {
+                outputs.push(value);
+                if let [x, y, z] = outputs[..] {
+                    screen.update(parse_event(x, y, z)?);
+                    outputs.clear();
+                }
+            }
+            RunState::NeedsInput => match joystick(screen) {
+                Some(joystick) => computer.push_input(joystick.input()),
+                None => return Ok(false),
+            },
+            RunState::Halted => return Ok(true),
+        }
+    }
+}
 
-        grid.insert(position, tile);
+fn parse_event(x: i64, y: i64, z: i64) -> Result<Event, ArcadeError> {
+    match (x, y, z) {
+        (-1, 0, score) => usize::try_from(score)
+            .map(Event::ScoreUpdate)
+            .map_err(|_| ArcadeError::NegativeScore(score)),
+        (x, y, z) => Ok(Event::TileUpdate(Point::new(x, y), parse_tile(z)?)),
     }
 }
 
-fn parse_tile(instruction: i64) -> Tile {
+fn parse_tile(instruction: i64) -> Result<Tile, ArcadeError> {
     match instruction {
-        0 => Tile::Empty,
-        1 => Tile::Wall,
-        2 => Tile::Block,
-        3 => Tile::HorizontalPaddle,
-        4 => Tile::Ball,
-        _ => panic!("unknown tile instruction {}", instruction),
+        0 => Ok(Tile::Empty),
+        1 => Ok(Tile::Wall),
+        2 => Ok(Tile::Block),
+        3 => Ok(Tile::HorizontalPaddle),
+        4 => Ok(Tile::Ball),
+        _ => Err(ArcadeError::UnknownTile(instruction)),
+    }
+}
+
+/// Draw over the last frame, rows are always the same width so nothing needs clearing
+fn draw(out: &mut impl Write, screen: &Screen, status: &str) -> io::Result<()> {
+    let frame = screen.to_string();
+    let mut rows: u16 = 0;
+    for (row, line) in frame.lines().enumerate() {
+        queue!(out, cursor::MoveTo(0, row as u16), Print(line))?;
+        rows += 1;
+    }
+    queue!(
+        out,
+        terminal::Clear(ClearType::UntilNewLine),
+        cursor::MoveTo(0, rows + 1),
+        Print(status),
+        terminal::Clear(ClearType::UntilNewLine)
+    )?;
+
+    out.flush()
+}
+
+/// Wait out a frame and return the last direction pressed during it, neutral if none was.
+/// Returns none if the player quit
+fn read_joystick(frame_time: Duration) -> io::Result<Option<Joystick>> {
+    let deadline = Instant::now() + frame_time;
+    let mut joystick = Joystick::Neutral;
+
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if !event::poll(remaining)? {
+            return Ok(Some(joystick));
+        }
+
+        let TerminalEvent::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind == KeyEventKind::Release {
+            continue;
+        }
+        match key.code {
+            KeyCode::Left | KeyCode::Char('a') => joystick = Joystick::Left,
+            KeyCode::Right | KeyCode::Char('d') => joystick = Joystick::Right,
+            KeyCode::Down | KeyCode::Char('s') | KeyCode::Char(' ') => joystick = Joystick::Neutral,
+            KeyCode::Esc | KeyCode::Char('q') => return Ok(None),
+            // Raw mode swallows the interrupt so handle it here
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return Ok(None),
+            _ => {}
+        }
     }
 }
@@ -1,28 +1,52 @@
 use std::sync::mpsc;
 
+use aoc_solution::{puzzle, Solution};
 use itertools::Itertools;
 
-use crate::computer::{parse_tape, Computer};
+use crate::computer::{parse_tape, Computer, ComputerError};
 
-pub fn part1(input: &str) -> usize {
+pub struct Day2;
+
+impl Solution for Day2 {
+    fn part1(&self, input: &str) -> String {
+        part1(input)
+            .expect("tape should run without error")
+            .to_string()
+    }
+
+    fn part2(&self, input: &str) -> Option<String> {
+        Some(
+            part2(input)
+                .expect("tape should run without error")
+                .to_string(),
+        )
+    }
+}
+
+puzzle!(2019, 2, Day2, "src/inputs/day2_input.txt");
+
+pub fn part1(input: &str) -> Result<usize, ComputerError> {
     let mut tape = parse_tape(input);
+    // Memory past the end of the tape is zeroed, make room for the noun and verb
+    tape.resize(tape.len().max(3), 0);
     tape[1] = 12;
     tape[2] = 2;
 
     let (sender, receiver) = mpsc::channel();
 
     let mut computer = Computer::load(&tape, receiver, sender);
-    computer.run();
+    computer.run()?;
 
     let final_tape = computer.dump_tape();
 
-    final_tape[0] as usize
+    Ok(final_tape[0] as usize)
 }
 
-pub fn part2(input: &str) -> usize {
+pub fn part2(input: &str) -> Result<usize, ComputerError> {
     let goal = 19690720;
 
     let mut tape = parse_tape(input);
+    tape.resize(tape.len().max(3), 0);
 
     for (noun, verb) in (0..=99).cartesian_product(0..=99) {
         tape[1] = noun;
@@ -31,11 +55,11 @@ pub fn part2(input: &str) -> usize {
         let (sender, receiver) = mpsc::channel();
 
         let mut computer = Computer::load(&tape, receiver, sender);
-        computer.run();
+        computer.run()?;
 
         let final_tape = computer.dump_tape();
         if final_tape[0] == goal {
-            return (100 * noun + verb) as usize;
+            return Ok((100 * noun + verb) as usize);
         }
     }
 
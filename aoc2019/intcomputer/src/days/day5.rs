@@ -1,8 +1,30 @@
 use std::sync::mpsc;
 
-use crate::computer::{parse_tape, Computer};
+use aoc_solution::{puzzle, Solution};
 
-pub fn both_parts(input: &str, user_input: i64) -> usize {
+use crate::computer::{parse_tape, Computer, ComputerError};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    fn part1(&self, input: &str) -> String {
+        both_parts(input, 1)
+            .expect("tape should run without error")
+            .to_string()
+    }
+
+    fn part2(&self, input: &str) -> Option<String> {
+        Some(
+            both_parts(input, 5)
+                .expect("tape should run without error")
+                .to_string(),
+        )
+    }
+}
+
+puzzle!(2019, 5, Day5, "src/inputs/day5_input.txt");
+
+pub fn both_parts(input: &str, user_input: i64) -> Result<usize, ComputerError> {
     let tape = parse_tape(input);
 
     let (in_sender, in_receiver) = mpsc::channel();
@@ -10,7 +32,7 @@ pub fn both_parts(input: &str, user_input: i64) -> usize {
 
     let mut computer = Computer::load(&tape, in_receiver, out_sender.clone());
     in_sender.send(user_input).expect("send should never close");
-    computer.run();
+    computer.run()?;
 
-    out_receiver.recv().expect("recv should never close") as usize
+    Ok(out_receiver.recv().expect("recv should never close") as usize)
 }
@@ -1,98 +1,253 @@
-use std::{sync::mpsc, thread};
-
+use std::{
+    collections::HashSet,
+    error::Error,
+    fmt,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+    thread,
+};
+
+use aoc_solution::{puzzle, Solution};
 use itertools::Itertools;
 
-use crate::computer::{parse_tape, Computer};
+use crate::{
+    computer::{parse_tape, Computer},
+    network::{Circuit, NetworkError},
+};
 
-pub fn part1(input: &str) -> usize {
-    let tape = parse_tape(input);
+pub struct Day7;
 
-    let mut max_signal = 0;
+impl Solution for Day7 {
+    fn part1(&self, input: &str) -> String {
+        part1(input)
+            .expect("circuit should run without error")
+            .to_string()
+    }
 
-    for permutation in (0i64..5).permutations(5) {
-        let (mut current_sender, mut current_receiver) = mpsc::channel();
-        // Challenge says to feed A a 0 to start the process so hang onto this
-        let first_sender = current_sender.clone();
-        let mut amplifiers = Vec::new();
+    fn part2(&self, input: &str) -> Option<String> {
+        Some(
+            part2(input)
+                .expect("circuit should run without error")
+                .to_string(),
+        )
+    }
+}
 
-        for phase_setting in permutation.iter() {
-            current_sender
-                .send(*phase_setting)
-                .expect("send should never close");
+puzzle!(2019, 7, Day7, "src/inputs/day7_input.txt");
 
-            let (next_sender, next_receiver) = mpsc::channel();
+pub fn part1(input: &str) -> Result<usize, NetworkError> {
+    let tape = parse_tape(input);
 
-            let amplifier = Computer::load(&tape, current_receiver, next_sender.clone());
+    let best = Amplifiers::new(5, 0..5)
+        .expect("phases are all different")
+        .search(&tape)?
+        .expect("no values output");
 
-            amplifiers.push(amplifier);
+    Ok(best.signal as usize)
+}
 
-            (current_receiver, current_sender) = (next_receiver, next_sender);
-        }
+pub fn part2(input: &str) -> Result<usize, NetworkError> {
+    let tape = parse_tape(input);
 
-        first_sender
-            .send(0)
-            .expect("first channel should never close");
+    let best = Amplifiers::new(5, 5..10)
+        .expect("phases are all different")
+        .feedback()
+        .search(&tape)?
+        .expect("no values output");
 
-        for mut amplifier in amplifiers {
-            amplifier.run();
-        }
+    Ok(best.signal as usize)
+}
+
+/// The phase settings that gave the strongest signal
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Best {
+    pub phase_settings: Vec<i64>,
+    pub signal: i64,
+}
+
+/// A phase that was given more than once
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicatePhase(pub i64);
 
-        let circuit_signal = current_receiver.recv().expect("recv should never close");
-        max_signal = max_signal.max(circuit_signal);
+impl fmt::Display for DuplicatePhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "phase {} is in the domain more than once", self.0)
     }
+}
+
+impl Error for DuplicatePhase {}
 
-    max_signal as usize
+/// A chain of amplifiers all running the same tape, searched for the ordering of phase settings
+/// that gives the strongest signal. Every amplifier gets a different phase from the domain
+#[derive(Debug, Clone)]
+pub struct Amplifiers {
+    count: usize,
+    phases: Vec<i64>,
+    feedback: bool,
+    threads: usize,
 }
 
-pub fn part2(input: &str) -> usize {
-    let tape = parse_tape(input);
-    let (results_send, results_recv) = mpsc::channel();
+impl Amplifiers {
+    pub fn new(
+        count: usize,
+        phases: impl IntoIterator<Item = i64>,
+    ) -> Result<Self, DuplicatePhase> {
+        let phases: Vec<i64> = phases.into_iter().collect();
+        let mut seen = HashSet::new();
+        if let Some(phase) = phases.iter().find(|phase| !seen.insert(**phase)) {
+            return Err(DuplicatePhase(*phase));
+        }
 
-    for permutation in (5i64..10).permutations(5) {
-        let (mut current_sender, mut current_receiver) = mpsc::channel();
-        // Challenge says to feed A a 0 to start the process so hang onto this
-        let first_sender = current_sender.clone();
+        Ok(Self {
+            count,
+            phases,
+            feedback: false,
+            threads: thread::available_parallelism().map_or(1, |n| n.get()),
+        })
+    }
 
-        for (i, phase_setting) in permutation.iter().enumerate() {
-            current_sender
-                .send(*phase_setting)
-                .expect("send should never close");
+    /// Loop the last amplifier back into the first instead of out of the circuit
+    pub fn feedback(mut self) -> Self {
+        self.feedback = true;
+        self
+    }
 
-            let (mut next_sender, next_receiver) = mpsc::channel();
+    /// How many threads to search on, defaults to one per core
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = threads.max(1);
+        self
+    }
 
-            // Final circuit needs to feed back to beginning
-            if i == 4 {
-                next_sender = first_sender.clone();
-            }
+    /// Run the circuit once with each amplifier given its phase setting, then a 0 into the
+    /// first. The signal is the last thing the last amplifier output
+    pub fn signal(
+        &self,
+        tape: &[i64],
+        phase_settings: &[i64],
+    ) -> Result<Option<i64>, NetworkError> {
+        if phase_settings.is_empty() {
+            return Ok(None);
+        }
 
-            let mut amplifier = Computer::load(&tape, current_receiver, next_sender.clone());
-            let results_send = results_send.clone();
+        let amplifiers = phase_settings
+            .iter()
+            .map(|phase_setting| {
+                let mut amplifier = Computer::new(tape);
+                amplifier.push_input(*phase_setting);
+                amplifier
+            })
+            .collect();
+
+        let mut circuit = if self.feedback {
+            Circuit::feedback(amplifiers)
+        } else {
+            Circuit::pipeline(amplifiers)
+        };
+        // Challenge says to feed A a 0 to start the process
+        circuit.push_input(0, 0);
+        circuit.run()?;
+
+        Ok(circuit.last_output(phase_settings.len() - 1))
+    }
 
-            thread::spawn(move || {
-                amplifier.run();
+    /// Try every ordering of phases, spread over the threads. Ties go to the lexicographically
+    /// smallest phase settings and errors come from the first ordering to fail, so the result
+    /// doesn't depend on scheduling. Will return none if no ordering gave a signal, or there are
+    /// fewer phases than amplifiers
+    pub fn search(&self, tape: &[i64]) -> Result<Option<Best>, NetworkError> {
+        if self.count == 0 {
+            return Ok(None);
+        }
 
-                // A's final position should have one message left on the queue - which is the
-                // output for that, alternative here is to make the final sender for E be a
-                // broadcast, or allow each computer to take multiple senders
-                if i == 0 {
-                    let unused_message = amplifier.receiver().recv();
-                    results_send.send(unused_message).expect("results send should not close");
+        let orderings = Mutex::new(
+            self.phases
+                .iter()
+                .copied()
+                .permutations(self.count)
+                .enumerate(),
+        );
+        let failed = AtomicBool::new(false);
+
+        let results: Vec<Result<Option<Best>, (usize, NetworkError)>> = thread::scope(|scope| {
+            let workers: Vec<_> = (0..self.threads)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut best: Option<Best> = None;
+
+                        while !failed.load(Ordering::Relaxed) {
+                            let Some((index, phase_settings)) = orderings.lock().unwrap().next()
+                            else {
+                                break;
+                            };
+                            let signal = match self.signal(tape, &phase_settings) {
+                                Ok(Some(signal)) => signal,
+                                Ok(None) => continue,
+                                Err(err) => {
+                                    failed.store(true, Ordering::Relaxed);
+                                    return Err((index, err));
+                                }
+                            };
+
+                            let candidate = Best {
+                                phase_settings,
+                                signal,
+                            };
+                            best = Some(match best {
+                                Some(best) => better(best, candidate),
+                                None => candidate,
+                            });
+                        }
+
+                        Ok(best)
+                    })
+                })
+                .collect();
+
+            workers
+                .into_iter()
+                .map(|worker| worker.join().expect("search thread panicked"))
+                .collect()
+        });
+
+        // Orderings before a failed one were already handed out and get run to the end, so the
+        // earliest failure is the same however the threads were scheduled
+        let mut overall = None;
+        let mut first_failure: Option<(usize, NetworkError)> = None;
+        for result in results {
+            match result {
+                Ok(Some(candidate)) => {
+                    overall = Some(match overall {
+                        Some(best) => better(best, candidate),
+                        None => candidate,
+                    });
                 }
-            });
-
-            (current_receiver, current_sender) = (next_receiver, next_sender);
+                Ok(None) => {}
+                Err((index, err)) => {
+                    if first_failure
+                        .as_ref()
+                        .is_none_or(|(first, _)| index < *first)
+                    {
+                        first_failure = Some((index, err));
+                    }
+                }
+            }
         }
 
-        // Kickstart the circuit
-        first_sender
-            .send(0)
-            .expect("first sender should never close");
+        match first_failure {
+            Some((_index, err)) => Err(err),
+            None => Ok(overall),
+        }
     }
+}
 
-    drop(results_send);
-    results_recv
-        .iter()
-        .map(|x| x.expect("results recv should not close"))
-        .max()
-        .expect("no values output") as usize
+fn better(a: Best, b: Best) -> Best {
+    let a_wins =
+        a.signal > b.signal || (a.signal == b.signal && a.phase_settings < b.phase_settings);
+    if a_wins {
+        a
+    } else {
+        b
+    }
 }
@@ -1,35 +1,57 @@
 use std::sync::mpsc;
 
-use crate::computer::{parse_tape, Computer};
+use aoc_solution::{puzzle, Solution};
 
-pub fn part1(input: &str) -> usize {
+use crate::computer::{parse_tape, Computer, ComputerError};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    fn part1(&self, input: &str) -> String {
+        part1(input)
+            .expect("tape should run without error")
+            .to_string()
+    }
+
+    fn part2(&self, input: &str) -> Option<String> {
+        Some(
+            part2(input)
+                .expect("tape should run without error")
+                .to_string(),
+        )
+    }
+}
+
+puzzle!(2019, 9, Day9, "src/inputs/day9_input.txt");
+
+pub fn part1(input: &str) -> Result<usize, ComputerError> {
     let tape = parse_tape(input);
     let (in_sender, in_receiver) = mpsc::channel();
     let (out_sender, out_receiver) = mpsc::channel();
 
     let mut computer = Computer::load(&tape, in_receiver, out_sender.clone());
     in_sender.send(1).expect("in sender should never close");
-    computer.run();
+    computer.run()?;
 
     drop(out_sender);
-    out_receiver
+    Ok(out_receiver
         .iter()
         .next()
-        .expect("no values output") as usize
+        .expect("no values output") as usize)
 }
 
-pub fn part2(input: &str) -> usize {
+pub fn part2(input: &str) -> Result<usize, ComputerError> {
     let tape = parse_tape(input);
     let (in_sender, in_receiver) = mpsc::channel();
     let (out_sender, out_receiver) = mpsc::channel();
 
     let mut computer = Computer::load(&tape, in_receiver, out_sender.clone());
     in_sender.send(2).expect("in sender should never close");
-    computer.run();
+    computer.run()?;
 
     drop(out_sender);
-    out_receiver
+    Ok(out_receiver
         .iter()
         .next()
-        .expect("no values output") as usize
+        .expect("no values output") as usize)
 }
@@ -0,0 +1,292 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io::{self, BufRead, Write},
+};
+
+use crate::{
+    computer::{Computer, RunState},
+    disasm::describe,
+};
+
+const HELP: &str = "\
+s, step [n]         run n instructions (default 1), stopping early like continue
+c, continue         run until a breakpoint, watchpoint, input is needed or halt
+b, break <addr>     break when the pointer reaches addr
+bo <opcode>         break before any instruction with opcode (1-9, 99)
+w, watch <addr>     stop after the value at addr changes
+d, delete <addr>    remove breakpoints and watchpoints on addr, and opcode breaks on it
+x <addr> [n]        print n memory cells from addr (default 1)
+set <addr> <value>  write value to memory at addr
+rb [value]          print or set the relative base
+ip [addr]           print or set the instruction pointer
+i, info             show the instruction at the pointer, breakpoints and queued input
+in <values..>       queue input values
+q, quit             leave the debugger";
+
+/// Drives a computer a command at a time
+pub struct Debugger {
+    computer: Computer,
+    breakpoints: BTreeSet<usize>,
+    opcode_breaks: BTreeSet<i64>,
+    /// Watched addresses with the value they had when last checked
+    watchpoints: BTreeMap<usize, i64>,
+}
+
+impl Debugger {
+    pub fn new(computer: Computer) -> Self {
+        Self {
+            computer,
+            breakpoints: BTreeSet::new(),
+            opcode_breaks: BTreeSet::new(),
+            watchpoints: BTreeMap::new(),
+        }
+    }
+
+    pub fn computer(&self) -> &Computer {
+        &self.computer
+    }
+
+    /// Read commands until quit or the input runs out
+    pub fn repl(&mut self, input: impl BufRead, mut out: impl Write) -> io::Result<()> {
+        writeln!(out, "type h for help")?;
+        self.show_location(&mut out)?;
+        write!(out, "> ")?;
+        out.flush()?;
+
+        for line in input.lines() {
+            if !self.command(&line?, &mut out)? {
+                break;
+            }
+            write!(out, "> ")?;
+            out.flush()?;
+        }
+
+        Ok(())
+    }
+
+    /// Carry out one command, returns false on quit
+    pub fn command(&mut self, line: &str, out: &mut impl Write) -> io::Result<bool> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let Some((&command, args)) = words.split_first() else {
+            return Ok(true);
+        };
+
+        let numbers: Result<Vec<i64>, _> = args.iter().map(|arg| arg.parse::<i64>()).collect();
+        let Ok(numbers) = numbers else {
+            writeln!(out, "arguments should be numbers")?;
+            return Ok(true);
+        };
+        let address = |i: usize| numbers.get(i).and_then(|n| usize::try_from(*n).ok());
+
+        match (command, numbers.as_slice()) {
+            ("s" | "step", []) => self.run(Some(1), out)?,
+            ("s" | "step", [n]) => self.run(Some(usize::try_from(*n).unwrap_or(0)), out)?,
+            ("c" | "continue", []) => self.run(None, out)?,
+            ("b" | "break", [_]) => match address(0) {
+                Some(address) => {
+                    self.breakpoints.insert(address);
+                }
+                None => writeln!(out, "addresses can't be negative")?,
+            },
+            ("bo", [opcode]) => {
+                self.opcode_breaks.insert(*opcode);
+            }
+            ("w" | "watch", [_]) => match address(0) {
+                Some(address) => {
+                    let value = self.computer.memory().read(address);
+                    self.watchpoints.insert(address, value);
+                }
+                None => writeln!(out, "addresses can't be negative")?,
+            },
+            ("d" | "delete", [n]) => {
+                if let Some(address) = address(0) {
+                    self.breakpoints.remove(&address);
+                    self.watchpoints.remove(&address);
+                }
+                self.opcode_breaks.remove(n);
+            }
+            ("x", [_]) | ("x", [_, _]) => {
+                let count = numbers.get(1).copied().unwrap_or(1).max(0) as usize;
+                match address(0) {
+                    Some(start) => {
+                        for address in start..start + count {
+                            let value = self.computer.memory().read(address);
+                            writeln!(out, "[{address}] = {value}")?;
+                        }
+                    }
+                    None => writeln!(out, "addresses can't be negative")?,
+                }
+            }
+            ("set", [_, value]) => match address(0) {
+                Some(address) => {
+                    self.computer.write_memory(address, *value);
+                    // Don't report our own write as a watch hit
+                    if let Some(watched) = self.watchpoints.get_mut(&address) {
+                        *watched = *value;
+                    }
+                }
+                None => writeln!(out, "addresses can't be negative")?,
+            },
+            ("rb", []) => writeln!(out, "rb = {}", self.computer.relative_base())?,
+            ("rb", [base]) => self.computer.set_relative_base(*base),
+            ("ip", []) => self.show_location(out)?,
+            ("ip", [_]) => match address(0) {
+                Some(address) => {
+                    self.computer.set_instruction_ptr(address);
+                    self.show_location(out)?;
+                }
+                None => writeln!(out, "addresses can't be negative")?,
+            },
+            ("i" | "info", []) => self.info(out)?,
+            ("in", values) if !values.is_empty() => self.computer.push_inputs(values.to_vec()),
+            ("q" | "quit", []) => return Ok(false),
+            ("h" | "help", []) => writeln!(out, "{HELP}")?,
+            _ => writeln!(out, "unknown command, type h for help")?,
+        }
+
+        Ok(true)
+    }
+
+    /// Step until limit instructions have run or something stops it, none runs until stopped
+    fn run(&mut self, limit: Option<usize>, out: &mut impl Write) -> io::Result<()> {
+        let mut steps = 0;
+
+        loop {
+            if limit.is_some_and(|limit| steps >= limit) {
+                break;
+            }
+
+            // Always run at least one instruction so a breakpoint can be stepped off
+            if steps > 0 {
+                if let Some(reason) = self.break_reason() {
+                    writeln!(out, "{reason}")?;
+                    break;
+                }
+            }
+
+            match self.computer.step() {
+                Ok(None) => {}
+                Ok(Some(RunState::Output(value))) => writeln!(out, "output: {value}")?,
+                Ok(Some(RunState::NeedsInput)) => {
+                    writeln!(out, "waiting for input, queue some with in")?;
+                    break;
+                }
+                Ok(Some(RunState::Halted)) => {
+                    writeln!(out, "halted")?;
+                    break;
+                }
+                Err(err) => {
+                    writeln!(out, "error: {err}")?;
+                    break;
+                }
+            }
+            steps += 1;
+
+            if self.check_watchpoints(out)? {
+                break;
+            }
+        }
+
+        self.show_location(out)
+    }
+
+    fn break_reason(&self) -> Option<String> {
+        let ip = self.computer.instruction_ptr();
+        if self.breakpoints.contains(&ip) {
+            return Some(format!("breakpoint at {ip}"));
+        }
+
+        let opcode = self.computer.memory().read(ip) % 100;
+        self.opcode_breaks
+            .contains(&opcode)
+            .then(|| format!("opcode {opcode} breakpoint at {ip}"))
+    }
+
+    /// Report every watched cell that changed, returns whether any did
+    fn check_watchpoints(&mut self, out: &mut impl Write) -> io::Result<bool> {
+        let mut hit = false;
+
+        for (address, last) in self.watchpoints.iter_mut() {
+            let value = self.computer.memory().read(*address);
+            if value != *last {
+                writeln!(out, "watch [{address}]: {last} -> {value}")?;
+                *last = value;
+                hit = true;
+            }
+        }
+
+        Ok(hit)
+    }
+
+    fn show_location(&self, out: &mut impl Write) -> io::Result<()> {
+        let ip = self.computer.instruction_ptr();
+        writeln!(out, "{ip:04}: {}", describe(self.computer.memory(), ip))
+    }
+
+    fn info(&self, out: &mut impl Write) -> io::Result<()> {
+        self.show_location(out)?;
+        writeln!(out, "rb = {}", self.computer.relative_base())?;
+        writeln!(out, "breakpoints: {:?}", self.breakpoints)?;
+        writeln!(out, "opcode breaks: {:?}", self.opcode_breaks)?;
+        writeln!(out, "watchpoints: {:?}", self.watchpoints)?;
+        let inputs: Vec<&i64> = self.computer.queued_inputs().collect();
+        writeln!(out, "queued input: {inputs:?}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asm::assemble;
+
+    /// Run a script of commands and return everything printed
+    fn session(source: &str, script: &str) -> String {
+        let tape = assemble(source).unwrap();
+        let mut debugger = Debugger::new(Computer::new(&tape));
+        let mut out = Vec::new();
+
+        debugger.repl(script.as_bytes(), &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    const DOUBLER: &str = "
+        IN -> [10]
+        MULT [10], #2 -> [10]
+        OUT [10]
+        HALT
+    ";
+
+    #[test]
+    fn steps_and_feeds_input() {
+        let out = session(DOUBLER, "s\nin 21\ns 2\nc\n");
+
+        assert!(out.contains("waiting for input"));
+        assert!(out.contains("0006: OUT [10]"));
+        assert!(out.contains("output: 42"));
+        assert!(out.contains("halted"));
+    }
+
+    #[test]
+    fn stops_on_breakpoints_and_watchpoints() {
+        let out = session(DOUBLER, "in 5\nb 6\nc\nd 6\nw 10\nbo 99\nc\nc\n");
+
+        assert!(out.contains("breakpoint at 6"));
+        assert!(out.contains("output: 10"));
+        assert!(out.contains("opcode 99 breakpoint at 8"));
+        assert!(!out.contains("watch [10]"));
+
+        let out = session(DOUBLER, "w 10\nin 5\nc\nc\n");
+        assert!(out.contains("watch [10]: 0 -> 5"));
+        assert!(out.contains("watch [10]: 5 -> 10"));
+    }
+
+    #[test]
+    fn edits_memory_and_registers() {
+        let out = session(DOUBLER, "set 12 7\nx 11 2\nrb -3\nrb\nip 6\nq\nx 0\n");
+
+        assert!(out.contains("[11] = 0\n[12] = 7"));
+        assert!(out.contains("rb = -3"));
+        assert!(out.contains("0006: OUT [10]"));
+        assert!(!out.contains("[0] ="));
+    }
+}
@@ -0,0 +1,296 @@
+use std::{collections::BTreeSet, fmt};
+
+use crate::{
+    computer::{decode, Instruction, Parameter},
+    memory::Memory,
+};
+
+/// Most values put on one DATA line
+const DATA_PER_LINE: usize = 8;
+
+/// One line of a listing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Line {
+    /// A decoded instruction taking up len cells from address
+    Code {
+        address: usize,
+        len: usize,
+        text: String,
+    },
+    /// Cells that don't decode as an instruction
+    Data { address: usize, values: Vec<i64> },
+}
+
+impl Line {
+    pub fn address(&self) -> usize {
+        match self {
+            Line::Code { address, .. } | Line::Data { address, .. } => *address,
+        }
+    }
+}
+
+/// A listing of a whole tape, labels are the addresses jumped to by immediate jumps
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Disassembly {
+    pub lines: Vec<Line>,
+    pub labels: BTreeSet<usize>,
+}
+
+/// Linear sweep over the tape. Anything that doesn't decode, would write to an immediate or runs
+/// off the end of the tape is data. Jump targets found on a first sweep are kept as instruction
+/// boundaries on the second, so code after a data region lines up with the jumps into it
+pub fn disassemble(tape: &[i64]) -> Disassembly {
+    let mem = Memory::from_tape(tape);
+
+    let first = sweep(&mem, tape.len(), &BTreeSet::new());
+    let boundaries = jump_targets(&first, tape.len());
+    let decoded = sweep(&mem, tape.len(), &boundaries);
+
+    let starts: BTreeSet<usize> = decoded.iter().map(|(address, _)| *address).collect();
+    let labels = jump_targets(&decoded, tape.len())
+        .intersection(&starts)
+        .copied()
+        .collect();
+
+    let mut lines: Vec<Line> = Vec::new();
+    for (address, decoded) in decoded {
+        let line = match decoded {
+            Decoded::Code(instruction) => Line::Code {
+                address,
+                len: instruction.len(),
+                text: format_instruction(&instruction, &labels),
+            },
+            Decoded::Halt => Line::Code {
+                address,
+                len: 1,
+                text: "HALT".to_string(),
+            },
+            Decoded::Data => {
+                // Extend the previous data line unless it is full or a label starts here
+                if let Some(Line::Data { values, .. }) = lines.last_mut() {
+                    if values.len() < DATA_PER_LINE && !labels.contains(&address) {
+                        values.push(tape[address]);
+                        continue;
+                    }
+                }
+                Line::Data {
+                    address,
+                    values: vec![tape[address]],
+                }
+            }
+        };
+        lines.push(line);
+    }
+
+    Disassembly { lines, labels }
+}
+
+/// The instruction at address as it would be listed, or why it can't be decoded
+/// Example: describe(computer.memory(), computer.instruction_ptr())
+pub fn describe(mem: &Memory, address: usize) -> String {
+    match decode(mem, address) {
+        Ok(Some(instruction)) => format_instruction(&instruction, &BTreeSet::new()),
+        Ok(None) => "HALT".to_string(),
+        Err(err) => format!("DATA {} ({err})", mem.read(address)),
+    }
+}
+
+/// What a sweep found at a line start
+enum Decoded {
+    Code(Instruction),
+    Halt,
+    Data,
+}
+
+impl Decoded {
+    fn len(&self) -> usize {
+        match self {
+            Decoded::Code(instruction) => instruction.len(),
+            Decoded::Halt | Decoded::Data => 1,
+        }
+    }
+}
+
+fn sweep(mem: &Memory, tape_len: usize, boundaries: &BTreeSet<usize>) -> Vec<(usize, Decoded)> {
+    let mut decoded = Vec::new();
+    let mut address = 0;
+
+    while address < tape_len {
+        let mut found = match decode(mem, address) {
+            Ok(Some(instruction)) if writes_to_memory(&instruction) => Decoded::Code(instruction),
+            Ok(None) => Decoded::Halt,
+            _ => Decoded::Data,
+        };
+
+        let end = address + found.len();
+        if end > tape_len || boundaries.range(address + 1..end).next().is_some() {
+            found = Decoded::Data;
+        }
+
+        let len = found.len();
+        decoded.push((address, found));
+        address += len;
+    }
+
+    decoded
+}
+
+/// Immediate jump targets that land on the tape
+fn jump_targets(decoded: &[(usize, Decoded)], tape_len: usize) -> BTreeSet<usize> {
+    decoded
+        .iter()
+        .filter_map(|(_, decoded)| match decoded {
+            Decoded::Code(
+                Instruction::JumpIfTrue(_, Parameter::Immediate(target))
+                | Instruction::JumpIfFalse(_, Parameter::Immediate(target)),
+            ) => usize::try_from(*target).ok(),
+            _ => None,
+        })
+        .filter(|target| *target < tape_len)
+        .collect()
+}
+
+/// Whether every write parameter is something that can be written to
+fn writes_to_memory(instruction: &Instruction) -> bool {
+    let dest = match instruction {
+        Instruction::Add(_, _, dest)
+        | Instruction::Mult(_, _, dest)
+        | Instruction::LessThan(_, _, dest)
+        | Instruction::Equals(_, _, dest)
+        | Instruction::Input(dest) => dest,
+        _ => return true,
+    };
+
+    !matches!(dest, Parameter::Immediate(_))
+}
+
+pub(crate) fn format_instruction(instruction: &Instruction, labels: &BTreeSet<usize>) -> String {
+    let jump = |target: &Parameter| match target {
+        Parameter::Immediate(address) => match usize::try_from(*address) {
+            Ok(address) if labels.contains(&address) => label(address),
+            _ => operand(*target),
+        },
+        _ => operand(*target),
+    };
+
+    match instruction {
+        Instruction::Add(a, b, c) => binary("ADD", a, b, c),
+        Instruction::Mult(a, b, c) => binary("MULT", a, b, c),
+        Instruction::LessThan(a, b, c) => binary("LT", a, b, c),
+        Instruction::Equals(a, b, c) => binary("EQ", a, b, c),
+        Instruction::Input(dest) => format!("IN -> {}", operand(*dest)),
+        Instruction::Output(a) => format!("OUT {}", operand(*a)),
+        Instruction::JumpIfTrue(cond, target) => format!("JT {}, {}", operand(*cond), jump(target)),
+        Instruction::JumpIfFalse(cond, target) => {
+            format!("JF {}, {}", operand(*cond), jump(target))
+        }
+        Instruction::AdjustRelativeBase(a) => format!("ARB {}", operand(*a)),
+    }
+}
+
+fn binary(name: &str, a: &Parameter, b: &Parameter, dest: &Parameter) -> String {
+    format!(
+        "{name} {}, {} -> {}",
+        operand(*a),
+        operand(*b),
+        operand(*dest)
+    )
+}
+
+/// `[n]` for position, `#n` for immediate and `[rb+n]` for relative
+fn operand(parameter: Parameter) -> String {
+    match parameter {
+        Parameter::Position(address) => format!("[{address}]"),
+        Parameter::Immediate(value) => format!("#{value}"),
+        Parameter::Relative(offset) if offset < 0 => format!("[rb{offset}]"),
+        Parameter::Relative(offset) => format!("[rb+{offset}]"),
+    }
+}
+
+fn label(address: usize) -> String {
+    format!("L{address:04}")
+}
+
+impl fmt::Display for Disassembly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for line in self.lines.iter() {
+            if self.labels.contains(&line.address()) {
+                writeln!(f, "{}:", label(line.address()))?;
+            }
+
+            match line {
+                Line::Code { address, text, .. } => writeln!(f, "{address:04}: {text}")?,
+                Line::Data { address, values } => {
+                    let values: Vec<String> = values.iter().map(i64::to_string).collect();
+                    writeln!(f, "{address:04}: DATA {}", values.join(", "))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_operands() {
+        assert_eq!(operand(Parameter::Position(104)), "[104]");
+        assert_eq!(operand(Parameter::Immediate(-5)), "#-5");
+        assert_eq!(operand(Parameter::Relative(3)), "[rb+3]");
+        assert_eq!(operand(Parameter::Relative(-3)), "[rb-3]");
+    }
+
+    #[test]
+    fn immediate_writes_are_data() {
+        // The cells after aren't code either, their instructions would run off the tape
+        let listing = disassemble(&[11101, 1, 2, 99]);
+
+        assert_eq!(
+            listing.lines,
+            [
+                Line::Data {
+                    address: 0,
+                    values: vec![11101, 1, 2]
+                },
+                Line::Code {
+                    address: 3,
+                    len: 1,
+                    text: "HALT".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn jump_targets_become_labels() {
+        let listing = disassemble(&[1105, 1, 6, 104, 5, 99, 4, 0, 99]);
+
+        assert_eq!(
+            listing.to_string(),
+            "0000: JT #1, L0006\n\
+             0003: OUT #5\n\
+             0005: HALT\n\
+             L0006:\n\
+             0006: OUT [0]\n\
+             0008: HALT\n"
+        );
+    }
+
+    #[test]
+    fn realigns_on_jump_targets() {
+        // Without the label 7 at address 3 would swallow the output as LT [104], [1] -> [99]
+        let listing = disassemble(&[1105, 1, 4, 7, 104, 1, 99]);
+
+        assert_eq!(
+            listing.to_string(),
+            "0000: JT #1, L0004\n\
+             0003: DATA 7\n\
+             L0004:\n\
+             0004: OUT #1\n\
+             0006: HALT\n"
+        );
+    }
+}
@@ -1,5 +1,14 @@
+pub mod ascii;
+pub mod asm;
+mod compiled;
 pub mod computer;
 pub mod days;
+pub mod debugger;
+pub mod disasm;
+pub mod memory;
+pub mod network;
+pub mod state;
+pub mod trace;
 
 #[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub struct Point {
@@ -1,16 +1,87 @@
-use clap::Parser;
-use intcomputer::days::*;
+use clap::{Args, Parser, Subcommand};
+use std::{
+    error::Error,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Duration,
+};
+
+use intcomputer::{
+    ascii::AsciiComputer,
+    computer::{try_parse_tape, Computer},
+    days::{day13::Moves, *},
+    debugger::Debugger,
+    disasm::disassemble,
+};
 
 #[derive(Parser)]
-#[command(name = "AoC Intcomputer")]
+#[command(name = "AoC Intcomputer", subcommand_negates_reqs = true)]
 struct Cli {
-    #[arg(short, long, value_parser = parse_day)]
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[arg(short, long, value_parser = parse_day, required = true)]
     /// The day of the month (1-24)
-    day: u8,
+    day: Option<u8>,
 
-    #[arg(short, long, value_parser = parse_part)]
+    #[arg(short, long, value_parser = parse_part, required = true)]
     /// The part of the challenge (1 or 2)
-    part: u8,
+    part: Option<u8>,
+
+    #[arg(short, long)]
+    /// Read the tape from this file, or stdin for -. Defaults to the day's input
+    input: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Print a listing of a tape
+    Disasm(Source),
+    /// Step through a tape interactively
+    Debug(Source),
+    /// Play a text based tape by typing lines to it
+    Ascii(Source),
+    /// Run a tape with the given inputs and print every output
+    Run {
+        #[command(flatten)]
+        source: Source,
+
+        #[arg(allow_negative_numbers = true)]
+        /// Values to queue as input before running
+        values: Vec<i64>,
+    },
+    /// Play the day 13 arcade game with the arrow keys
+    Arcade {
+        #[command(flatten)]
+        source: Source,
+
+        #[arg(long)]
+        /// Save every move made to this file
+        record: Option<PathBuf>,
+
+        #[arg(long)]
+        /// Make the moves saved in this file before handing over to the keyboard
+        replay: Option<PathBuf>,
+
+        #[arg(long, default_value_t = 100)]
+        /// Milliseconds between frames
+        frame_ms: u64,
+    },
+}
+
+/// Where a subcommand gets its tape from, stdin if neither is given. The arcade defaults to day 13
+/// and debug and ascii need one or the other
+#[derive(Args)]
+struct Source {
+    #[arg(short, long, value_parser = parse_day, conflicts_with = "input")]
+    /// Use the input for this day of the month (1-24)
+    day: Option<u8>,
+
+    #[arg(short, long)]
+    /// Read the tape from this file, or stdin for -
+    input: Option<PathBuf>,
 }
 
 fn parse_day(day: &str) -> Result<u8, String> {
@@ -21,32 +92,136 @@ fn parse_part(part: &str) -> Result<u8, String> {
     Ok(part.parse().expect("part should be 1 or 2"))
 }
 
-fn main() {
-    let args = Cli::parse();
-
-    let day2_input = include_str!("inputs/day2_input.txt");
-    let day5_input = include_str!("inputs/day5_input.txt");
-    let day7_input = include_str!("inputs/day7_input.txt");
-    let day9_input = include_str!("inputs/day9_input.txt");
-    let day11_input = include_str!("inputs/day11_input.txt");
-    let day13_input = include_str!("inputs/day13_input.txt");
-
-    match (args.day, args.part) {
-        (2, 1) => println!("Day 2 part 1: {}", day2::part1(day2_input)),
-        (2, 2) => println!("Day 2 part 2: {}", day2::part2(day2_input)),
-        (5, 1) => println!("Day 5 part 1: {}", day5::both_parts(day5_input, 1)),
-        (5, 2) => println!("Day 5 part 2: {}", day5::both_parts(day5_input, 5)),
-        (7, 1) => println!("Day 7 part 1: {}", day7::part1(day7_input)),
-        (7, 2) => println!("Day 7 part 2: {}", day7::part2(day7_input)),
-        (9, 1) => println!("Day 9 part 1: {}", day9::part1(day9_input)),
-        (9, 2) => println!("Day 9 part 2: {}", day9::part2(day9_input)),
-        (11, 1) => println!("Day 11 part 1: {}", day11::part1(day11_input)),
-        (11, 2) => {
-            println!("Day 11 part 2...");
-            day11::part2(day11_input);
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
         }
-        (13, 1) => println!("Day 13 part 1: {}", day13::part1(day13_input)),
-        (13, 2) => println!("Day 13 part 2: {}", day13::part2(day13_input)),
-        _ => eprintln!("Pick a proper day and part fool"),
     }
 }
+
+fn run(args: Cli) -> Result<(), Box<dyn Error>> {
+    if let Some(command) = args.command {
+        return Ok(run_command(command)?);
+    }
+
+    let day = args.day.expect("day is required without a subcommand");
+    let part = args.part.expect("part is required without a subcommand");
+    let path = args.input.unwrap_or_else(|| day_input(day));
+    let input = read_input(&path)?;
+    // Check the tape up front, the days expect it to parse
+    try_parse_tape(&input).map_err(|err| format!("{}: {err}", path.display()))?;
+    let input = input.as_str();
+
+    match (day, part) {
+        (2, 1) => println!("Day 2 part 1: {}", day2::part1(input)?),
+        (2, 2) => println!("Day 2 part 2: {}", day2::part2(input)?),
+        (5, 1) => println!("Day 5 part 1: {}", day5::both_parts(input, 1)?),
+        (5, 2) => println!("Day 5 part 2: {}", day5::both_parts(input, 5)?),
+        (7, 1) => println!("Day 7 part 1: {}", day7::part1(input)?),
+        (7, 2) => println!("Day 7 part 2: {}", day7::part2(input)?),
+        (9, 1) => println!("Day 9 part 1: {}", day9::part1(input)?),
+        (9, 2) => println!("Day 9 part 2: {}", day9::part2(input)?),
+        (11, 1) => println!("Day 11 part 1: {}", day11::part1(input)?),
+        (11, 2) => print!("Day 11 part 2:\n{}", day11::part2(input)?),
+        (13, 1) => println!("Day 13 part 1: {}", day13::part1(input)?),
+        (13, 2) => println!("Day 13 part 2: {}", day13::part2(input)?),
+        _ => return Err("Pick a proper day and part fool".into()),
+    }
+
+    Ok(())
+}
+
+fn run_command(command: Command) -> Result<(), String> {
+    let source = match &command {
+        Command::Disasm(source) | Command::Debug(source) | Command::Ascii(source) => source,
+        Command::Run { source, .. } | Command::Arcade { source, .. } => source,
+    };
+    let path = match (&source.input, source.day, &command) {
+        (Some(path), _, _) => path.clone(),
+        (None, Some(day), _) => day_input(day),
+        (None, None, Command::Arcade { .. }) => day_input(13),
+        (None, None, _) => PathBuf::from("-"),
+    };
+    // These read what's typed from stdin so the tape can't come from there too
+    if path == Path::new("-") && matches!(command, Command::Debug(_) | Command::Ascii(_)) {
+        return Err("debug and ascii read from stdin, give the tape with --input or --day".into());
+    }
+    let tape =
+        try_parse_tape(&read_input(&path)?).map_err(|err| format!("{}: {err}", path.display()))?;
+
+    match command {
+        Command::Disasm(_) => print!("{}", disassemble(&tape)),
+        Command::Debug(_) => {
+            let mut debugger = Debugger::new(Computer::new(&tape));
+            debugger
+                .repl(io::stdin().lock(), io::stdout())
+                .map_err(|err| err.to_string())?;
+        }
+        Command::Ascii(_) => {
+            let mut computer = AsciiComputer::from_tape(&tape);
+            computer
+                .play(io::stdin().lock())
+                .map_err(|err| format!("\n{err}"))?;
+        }
+        Command::Run { values, .. } => {
+            let mut computer = Computer::new(&tape).with_decode_cache();
+            computer.push_inputs(values);
+            for output in computer.run_to_halt().map_err(|err| err.to_string())? {
+                println!("{output}");
+            }
+        }
+        Command::Arcade {
+            record,
+            replay,
+            frame_ms,
+            ..
+        } => {
+            let replay = match replay {
+                Some(path) => read_input(&path)?
+                    .parse()
+                    .map_err(|err| format!("{}: {err}", path.display()))?,
+                None => Moves::default(),
+            };
+
+            let game = day13::play_in_terminal(
+                &day13::free_play(&tape),
+                &replay,
+                Duration::from_millis(frame_ms),
+            )
+            .map_err(|err| err.to_string())?;
+
+            println!("{}", game.screen);
+            if !game.finished {
+                println!("Quit with {} blocks left", game.screen.blocks());
+            }
+            if let Some(path) = record {
+                fs::write(&path, format!("{}\n", game.moves))
+                    .map_err(|err| format!("could not write {}: {err}", path.display()))?;
+                println!("Saved {} moves to {}", game.moves.0.len(), path.display());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Where a day's input lives in the crate
+fn day_input(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("src/inputs/day{day}_input.txt"))
+}
+
+/// Read a file, or stdin for -
+fn read_input(path: &Path) -> Result<String, String> {
+    if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|err| format!("could not read stdin: {err}"))?;
+        return Ok(input);
+    }
+
+    fs::read_to_string(path).map_err(|err| format!("could not read {}: {err}", path.display()))
+}
@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+
+/// Addresses below this are stored in one vec that grows on write, anything further is paged
+const DENSE_LIMIT: usize = 1 << 20;
+const PAGE_SIZE: usize = 1 << 10;
+
+/// Intcode memory, every address reads as 0 until it is written
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Memory {
+    dense: Vec<i64>,
+    pages: HashMap<usize, Box<[i64; PAGE_SIZE]>>,
+}
+
+impl Memory {
+    pub fn from_tape(tape: &[i64]) -> Self {
+        let mut mem = Self::default();
+        for (address, value) in tape.iter().enumerate() {
+            mem.write(address, *value);
+        }
+
+        mem
+    }
+
+    pub fn read(&self, address: usize) -> i64 {
+        if address < DENSE_LIMIT {
+            return self.dense.get(address).copied().unwrap_or(0);
+        }
+
+        self.pages
+            .get(&(address / PAGE_SIZE))
+            .map_or(0, |page| page[address % PAGE_SIZE])
+    }
+
+    pub fn write(&mut self, address: usize, value: i64) {
+        if address < DENSE_LIMIT {
+            if address >= self.dense.len() {
+                self.dense.resize(address + 1, 0);
+            }
+            self.dense[address] = value;
+            return;
+        }
+
+        let page = self
+            .pages
+            .entry(address / PAGE_SIZE)
+            .or_insert_with(|| Box::new([0; PAGE_SIZE]));
+        page[address % PAGE_SIZE] = value;
+    }
+
+    /// Rebuild memory from what touched and far_pages gave back
+    pub fn from_parts(
+        dense: Vec<i64>,
+        far_pages: impl IntoIterator<Item = (usize, Vec<i64>)>,
+    ) -> Self {
+        let mut mem = Self {
+            dense,
+            pages: HashMap::new(),
+        };
+        for (start, values) in far_pages {
+            for (i, value) in values.into_iter().enumerate() {
+                mem.write(start + i, value);
+            }
+        }
+
+        mem
+    }
+
+    /// Memory from 0 up to the highest address written below the paged region
+    pub fn touched(&self) -> &[i64] {
+        &self.dense
+    }
+
+    /// Every far page that has been written, as (first address, values)
+    pub fn far_pages(&self) -> impl Iterator<Item = (usize, &[i64])> {
+        self.pages
+            .iter()
+            .map(|(page, values)| (page * PAGE_SIZE, values.as_slice()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grows_on_write() {
+        let mut mem = Memory::from_tape(&[1, 2, 3]);
+        assert_eq!(mem.read(50), 0);
+        assert_eq!(mem.touched().len(), 3);
+
+        mem.write(20_000, 7);
+        assert_eq!(mem.read(20_000), 7);
+        assert_eq!(mem.touched().len(), 20_001);
+    }
+
+    #[test]
+    fn far_addresses_are_paged() {
+        let mut mem = Memory::default();
+        let far = usize::MAX / 2;
+
+        mem.write(far, -5);
+        assert_eq!(mem.read(far), -5);
+        assert_eq!(mem.read(far + 1), 0);
+        assert!(mem.touched().is_empty());
+        assert_eq!(mem.far_pages().count(), 1);
+    }
+}
@@ -0,0 +1,242 @@
+use std::fmt;
+
+use crate::computer::{Computer, ComputerError, RunState};
+
+/// Address that packets are sent to for the NAT
+pub const NAT_ADDRESS: i64 = 255;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NetworkError {
+    /// A machine in the network failed
+    Computer { node: usize, error: ComputerError },
+    /// Nothing is running and nothing will ever arrive to wake the nodes that are waiting
+    Deadlock { waiting: Vec<usize> },
+    /// A packet was sent somewhere that isn't on the network
+    UnknownAddress { node: usize, address: i64 },
+}
+
+impl fmt::Display for NetworkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NetworkError::Computer { node, error } => write!(f, "node {node}: {error}"),
+            NetworkError::Deadlock { waiting } => {
+                write!(f, "deadlock with nodes {waiting:?} waiting for input")
+            }
+            NetworkError::UnknownAddress { node, address } => {
+                write!(f, "node {node} sent a packet to unknown address {address}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for NetworkError {}
+
+/// Run a node until it wants input it doesn't have or halts, returning what it output and
+/// whether it halted
+fn run_node(node: usize, computer: &mut Computer) -> Result<(Vec<i64>, bool), NetworkError> {
+    let mut outputs = Vec::new();
+    loop {
+        match computer.run_until_io() {
+            Ok(RunState::Output(value)) => outputs.push(value),
+            Ok(RunState::NeedsInput) => return Ok((outputs, false)),
+            Ok(RunState::Halted) => return Ok((outputs, true)),
+            Err(error) => return Err(NetworkError::Computer { node, error }),
+        }
+    }
+}
+
+/// Machines where everything one outputs goes to the input of another, or out of the circuit
+/// for None. Covers pipelines and feedback loops
+pub struct Circuit {
+    nodes: Vec<Computer>,
+    routes: Vec<Option<usize>>,
+    halted: Vec<bool>,
+    last_outputs: Vec<Option<i64>>,
+}
+
+impl Circuit {
+    /// Node i sends its outputs to routes[i]
+    pub fn new(nodes: Vec<Computer>, routes: Vec<Option<usize>>) -> Self {
+        assert_eq!(nodes.len(), routes.len(), "every node needs a route");
+
+        Self {
+            halted: vec![false; nodes.len()],
+            last_outputs: vec![None; nodes.len()],
+            nodes,
+            routes,
+        }
+    }
+
+    /// Each node feeds the next and the last one feeds out of the circuit
+    pub fn pipeline(nodes: Vec<Computer>) -> Self {
+        let len = nodes.len();
+        let routes = (1..=len).map(|next| (next < len).then_some(next)).collect();
+        Self::new(nodes, routes)
+    }
+
+    /// Each node feeds the next and the last one feeds back into the first
+    pub fn feedback(nodes: Vec<Computer>) -> Self {
+        let len = nodes.len();
+        let routes = (1..=len).map(|next| Some(next % len)).collect();
+        Self::new(nodes, routes)
+    }
+
+    pub fn push_input(&mut self, node: usize, value: i64) {
+        self.nodes[node].push_input(value);
+    }
+
+    /// The most recent value node output, wherever it went
+    pub fn last_output(&self, node: usize) -> Option<i64> {
+        self.last_outputs[node]
+    }
+
+    /// Run every node in order, each until it blocks, and repeat until they have all halted.
+    /// Returns everything sent out of the circuit
+    pub fn run(&mut self) -> Result<Vec<i64>, NetworkError> {
+        let mut external = Vec::new();
+
+        loop {
+            let mut delivered = false;
+
+            for node in 0..self.nodes.len() {
+                if self.halted[node] {
+                    continue;
+                }
+
+                let (outputs, halted) = run_node(node, &mut self.nodes[node])?;
+                self.halted[node] = halted;
+
+                for value in outputs {
+                    self.last_outputs[node] = Some(value);
+                    match self.routes[node] {
+                        Some(to) => {
+                            self.nodes[to].push_input(value);
+                            delivered |= !self.halted[to];
+                        }
+                        None => external.push(value),
+                    }
+                }
+            }
+
+            if self.halted.iter().all(|halted| *halted) {
+                return Ok(external);
+            }
+            if !delivered {
+                return Err(NetworkError::Deadlock {
+                    waiting: (0..self.nodes.len()).filter(|i| !self.halted[*i]).collect(),
+                });
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Packet {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// Something the NAT did, handed to the caller of `PacketNetwork::run`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NatEvent {
+    /// A packet was sent to the NAT
+    Received(Packet),
+    /// The network went idle so the NAT woke node 0 with its latest packet
+    Sent(Packet),
+}
+
+/// Machines that send each other (address, x, y) packets. Each node is booted with its address
+/// and reads -1 whenever it has nothing queued. Packets to 255 go to the NAT
+pub struct PacketNetwork {
+    nodes: Vec<Computer>,
+    halted: Vec<bool>,
+    /// Outputs of each node that aren't a whole packet yet
+    partial: Vec<Vec<i64>>,
+    nat: Option<Packet>,
+}
+
+impl PacketNetwork {
+    pub fn new(tape: &[i64], size: usize) -> Self {
+        let nodes = (0..size)
+            .map(|address| {
+                let mut computer = Computer::new(tape);
+                computer.push_input(address as i64);
+                computer
+            })
+            .collect();
+
+        Self {
+            nodes,
+            halted: vec![false; size],
+            partial: vec![Vec::new(); size],
+            nat: None,
+        }
+    }
+
+    /// Run the network in rounds, calling on_event for everything the NAT does until it returns
+    /// a value. Every node runs in address order each round until it blocks
+    /// Example: network.run(|event| match event { NatEvent::Received(packet) => Some(packet.y), _ => None })
+    pub fn run<T>(
+        &mut self,
+        mut on_event: impl FnMut(NatEvent) -> Option<T>,
+    ) -> Result<T, NetworkError> {
+        loop {
+            let mut sent = false;
+
+            for node in 0..self.nodes.len() {
+                if self.halted[node] {
+                    continue;
+                }
+                if self.nodes[node].queued_inputs().next().is_none() {
+                    self.nodes[node].push_input(-1);
+                }
+
+                let (outputs, halted) = run_node(node, &mut self.nodes[node])?;
+                self.halted[node] = halted;
+
+                for value in outputs {
+                    self.partial[node].push(value);
+                    let &[address, x, y] = self.partial[node].as_slice() else {
+                        continue;
+                    };
+                    self.partial[node].clear();
+                    sent = true;
+
+                    let packet = Packet { x, y };
+                    if address == NAT_ADDRESS {
+                        self.nat = Some(packet);
+                        if let Some(result) = on_event(NatEvent::Received(packet)) {
+                            return Ok(result);
+                        }
+                        continue;
+                    }
+
+                    let to = usize::try_from(address)
+                        .ok()
+                        .filter(|to| *to < self.nodes.len())
+                        .ok_or(NetworkError::UnknownAddress { node, address })?;
+                    self.nodes[to].push_inputs([x, y]);
+                }
+            }
+
+            // Packets queued for halted nodes will never be read so don't keep it busy
+            let waiting: Vec<usize> = (0..self.nodes.len()).filter(|i| !self.halted[*i]).collect();
+            let idle = waiting
+                .iter()
+                .all(|node| self.nodes[*node].queued_inputs().next().is_none());
+            if sent || !idle {
+                continue;
+            }
+
+            match self.nat {
+                Some(packet) if !waiting.is_empty() => {
+                    self.nodes[0].push_inputs([packet.x, packet.y]);
+                    if let Some(result) = on_event(NatEvent::Sent(packet)) {
+                        return Ok(result);
+                    }
+                }
+                _ => return Err(NetworkError::Deadlock { waiting }),
+            }
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{self, BufReader, BufWriter},
+    path::Path,
+};
+
+use serde::{Deserialize, Serialize};
+
+/// Everything needed to pick a computer back up where it left off. Saved as json, with far
+/// pages keyed by their first address so the same state always saves the same way
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComputerState {
+    pub instruction_ptr: usize,
+    pub relative_base: i64,
+    /// Inputs queued but not yet read
+    pub inputs: Vec<i64>,
+    /// Memory from address 0 up to the highest written below the paged region
+    pub memory: Vec<i64>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub far_pages: BTreeMap<usize, Vec<i64>>,
+}
+
+impl ComputerState {
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer(writer, self).map_err(io::Error::other)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let reader = BufReader::new(File::open(path)?);
+        serde_json::from_reader(reader).map_err(io::Error::other)
+    }
+}
@@ -0,0 +1,148 @@
+use std::{
+    collections::BTreeMap,
+    fmt,
+    io::{self, Write},
+};
+
+use serde::{Deserialize, Serialize};
+
+/// Mnemonics by opcode for the profile report
+const MNEMONICS: [(i64, &str); 9] = [
+    (1, "ADD"),
+    (2, "MULT"),
+    (3, "IN"),
+    (4, "OUT"),
+    (5, "JT"),
+    (6, "JF"),
+    (7, "LT"),
+    (8, "EQ"),
+    (9, "ARB"),
+];
+
+/// How many of the hottest addresses the report lists
+const REPORT_ADDRESSES: usize = 20;
+
+/// One executed instruction, written as a line of json in a trace file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TraceRecord {
+    /// How many instructions ran before this one
+    pub step: u64,
+    pub address: usize,
+    /// The raw opcode including parameter modes
+    pub opcode: i64,
+    /// As the disassembler would list it
+    pub instruction: String,
+    /// Values read for each parameter, or the address for parameters that are written to
+    pub operands: Vec<i64>,
+    /// (address, value) for every memory write
+    pub writes: Vec<(usize, i64)>,
+}
+
+/// Instruction counts from a traced run
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Profile {
+    pub total: u64,
+    pub per_address: BTreeMap<usize, u64>,
+    /// Keyed by the opcode without its parameter modes
+    pub per_opcode: BTreeMap<i64, u64>,
+}
+
+impl Profile {
+    fn count(&mut self, address: usize, opcode: i64) {
+        self.total += 1;
+        *self.per_address.entry(address).or_default() += 1;
+        *self.per_opcode.entry(opcode % 100).or_default() += 1;
+    }
+
+    /// Addresses by how often they ran, most first
+    pub fn hottest(&self) -> Vec<(usize, u64)> {
+        let mut addresses: Vec<(usize, u64)> =
+            self.per_address.iter().map(|(a, c)| (*a, *c)).collect();
+        addresses.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        addresses
+    }
+}
+
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} instructions executed", self.total)?;
+
+        writeln!(f, "\nby opcode:")?;
+        for (opcode, count) in self.per_opcode.iter() {
+            let name = MNEMONICS
+                .iter()
+                .find(|(op, _)| op == opcode)
+                .map_or("?", |(_, name)| name);
+            writeln!(f, "  {name:<4} {count:>12}")?;
+        }
+
+        writeln!(f, "\nhottest addresses:")?;
+        for (address, count) in self.hottest().into_iter().take(REPORT_ADDRESSES) {
+            writeln!(f, "  {address:04} {count:>12}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Collects a profile of every instruction a computer runs and optionally writes each one out
+/// as jsonl. Pass it to the `_traced` versions of the run methods
+pub struct Tracer {
+    writer: Option<Box<dyn Write>>,
+    profile: Profile,
+    /// First write failure, tracing stops writing after it
+    error: Option<io::Error>,
+}
+
+impl Tracer {
+    /// Only count instructions, nothing is written
+    pub fn profile_only() -> Self {
+        Self {
+            writer: None,
+            profile: Profile::default(),
+            error: None,
+        }
+    }
+
+    /// Write a line of json per instruction to writer as well as counting them
+    pub fn jsonl(writer: impl Write + 'static) -> Self {
+        Self {
+            writer: Some(Box::new(writer)),
+            ..Self::profile_only()
+        }
+    }
+
+    pub fn profile(&self) -> &Profile {
+        &self.profile
+    }
+
+    /// Called by the computer after every instruction, the step number is filled in here
+    pub(crate) fn record(&mut self, mut record: TraceRecord) {
+        record.step = self.profile.total;
+        self.profile.count(record.address, record.opcode);
+
+        if self.error.is_some() {
+            return;
+        }
+        if let Some(writer) = self.writer.as_mut() {
+            let written = serde_json::to_writer(&mut *writer, &record)
+                .map_err(io::Error::other)
+                .and_then(|_| writer.write_all(b"\n"));
+            if let Err(err) = written {
+                self.error = Some(err);
+            }
+        }
+    }
+
+    /// Flush the trace and return the profile, or the first error writing the trace
+    pub fn finish(mut self) -> io::Result<Profile> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+        if let Some(writer) = self.writer.as_mut() {
+            writer.flush()?;
+        }
+
+        Ok(self.profile)
+    }
+}
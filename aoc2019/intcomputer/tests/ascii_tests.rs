@@ -0,0 +1,54 @@
+use intcomputer::{
+    ascii::{AsciiComputer, Prompt},
+    asm::assemble,
+};
+
+/// Asks a question, echoes back one line then outputs the answer
+const ECHO: &str = "
+        OUT #63
+        OUT #10
+    loop:
+        IN -> [c]
+        OUT [c]
+        EQ [c], #10 -> [done]
+        JF [done], loop
+        OUT #1000
+        HALT
+    c:    DATA 0
+    done: DATA 0
+";
+
+#[test]
+fn lines_in_text_and_values_out() {
+    let mut computer = AsciiComputer::from_tape(&assemble(ECHO).unwrap());
+
+    assert_eq!(
+        computer.read_until_prompt(),
+        Ok(Prompt {
+            text: "?\n".to_string(),
+            values: vec![],
+            halted: false
+        })
+    );
+
+    computer.send_line("north");
+    assert_eq!(
+        computer.read_until_prompt(),
+        Ok(Prompt {
+            text: "north\n".to_string(),
+            values: vec![1000],
+            halted: true
+        })
+    );
+}
+
+#[test]
+fn play_stops_when_input_runs_out() {
+    let mut computer = AsciiComputer::from_tape(&assemble(ECHO).unwrap());
+
+    computer.play("".as_bytes()).unwrap();
+    assert!(computer.read_until_prompt().unwrap().text.is_empty());
+
+    computer.play("hi\n".as_bytes()).unwrap();
+    assert!(computer.read_until_prompt().unwrap().halted);
+}
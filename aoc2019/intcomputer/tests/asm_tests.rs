@@ -0,0 +1,70 @@
+use intcomputer::{
+    asm::assemble,
+    computer::{parse_tape, Computer},
+    disasm::disassemble,
+};
+
+#[test]
+fn sums_inputs_until_zero() {
+    let source = "
+        ; total lives at [total], each input is read into [next]
+        loop:
+            IN -> [next]
+            JF [next], done
+            ADD [total], [next] -> [total]
+            JT #1, loop
+        done:
+            OUT [total]
+            HALT
+        next:  DATA 0
+        total: DATA 0
+    ";
+    let tape = assemble(source).unwrap();
+
+    let mut computer = Computer::new(&tape);
+    computer.push_inputs([3, 4, 5, 0]);
+
+    assert_eq!(computer.run_to_halt(), Ok(vec![12]));
+}
+
+#[test]
+fn relative_mode_matches_day9() {
+    let source = "
+        ARB #1
+        OUT [rb-1]
+        HALT
+    ";
+
+    assert_eq!(assemble(source), Ok(vec![109, 1, 204, -1, 99]));
+}
+
+#[test]
+fn labels_can_start_with_rb() {
+    let source = "
+        ARB #5
+        ADD [rbuf], [rb] -> [rbuf]
+        OUT [rbuf]
+        HALT
+    rbuf: DATA 7
+    ";
+    let tape = assemble(source).unwrap();
+    assert_eq!(tape, vec![109, 5, 2001, 9, 0, 9, 4, 9, 99, 7]);
+
+    let listing = disassemble(&tape).to_string();
+    assert_eq!(assemble(&listing), Ok(tape));
+}
+
+#[test]
+fn round_trips_through_disassembler() {
+    let inputs = [
+        "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99",
+        "3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,1106,0,36,98,0,0,1002,21,125,20,4,20,1105,1,46,104,999,1105,1,46,1101,1000,1,20,4,20,1105,1,46,98,99",
+    ];
+
+    for input in inputs {
+        let tape = parse_tape(input);
+        let listing = disassemble(&tape).to_string();
+
+        assert_eq!(assemble(&listing), Ok(tape), "listing:\n{listing}");
+    }
+}
@@ -0,0 +1,64 @@
+use intcomputer::{
+    asm::assemble,
+    computer::{parse_tape, Computer, ComputerError},
+};
+
+type Outputs = Result<Vec<i64>, ComputerError>;
+
+fn both_engines(tape: &[i64], inputs: &[i64]) -> (Outputs, Outputs) {
+    let mut plain = Computer::new(tape);
+    let mut cached = Computer::new(tape).with_decode_cache();
+    plain.push_inputs(inputs.iter().copied());
+    cached.push_inputs(inputs.iter().copied());
+
+    (plain.run_to_halt(), cached.run_to_halt())
+}
+
+#[test]
+fn self_modifying_code_sees_its_writes() {
+    // The loop patches the immediate of its own output instruction
+    let source = "
+        start:
+            OUT #1
+            ADD [1], #1 -> [1]
+            LT [1], #3 -> [t]
+            JT [t], start
+            HALT
+        t: DATA 0
+    ";
+    let (plain, cached) = both_engines(&assemble(source).unwrap(), &[]);
+
+    assert_eq!(plain, Ok(vec![1, 2]));
+    assert_eq!(cached, plain);
+}
+
+#[test]
+fn matches_interpreter_on_examples() {
+    let day9_quine = "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99";
+    let day5_compare = "3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,1106,0,36,98,0,0,1002,21,125,20,4,20,1105,1,46,104,999,1105,1,46,1101,1000,1,20,4,20,1105,1,46,98,99";
+
+    let (plain, cached) = both_engines(&parse_tape(day9_quine), &[]);
+    assert_eq!(plain, Ok(parse_tape(day9_quine)));
+    assert_eq!(cached, plain);
+
+    for input in [7, 8, 9] {
+        let (plain, cached) = both_engines(&parse_tape(day5_compare), &[input]);
+        assert_eq!(cached, plain);
+    }
+}
+
+#[test]
+fn reports_the_same_errors() {
+    let tapes = [
+        vec![109, -5, 204, 0, 99],   // negative relative address
+        vec![1101, 1, 2, 7, 42, 99], // runs into an invalid opcode
+        vec![11101, 1, 2, 3, 99],    // write to an immediate
+        vec![3, 3, 99],              // runs out of input
+    ];
+
+    for tape in tapes {
+        let (plain, cached) = both_engines(&tape, &[]);
+        assert!(plain.is_err(), "{tape:?} should fail");
+        assert_eq!(cached, plain);
+    }
+}
@@ -0,0 +1,16 @@
+use intcomputer::days::day11::{part1, RobotError};
+
+#[test]
+fn paints_one_panel() {
+    assert_eq!(part1("104,1,104,0,99"), Ok(1));
+}
+
+#[test]
+fn reports_bad_robot_output() {
+    assert_eq!(part1("104,2,99"), Err(RobotError::UnexpectedOutput(2)));
+    assert_eq!(
+        part1("104,1,104,3,99"),
+        Err(RobotError::UnexpectedOutput(3))
+    );
+    assert_eq!(part1("104,1,99"), Err(RobotError::MissingTurn));
+}
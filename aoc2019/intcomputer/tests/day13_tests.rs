@@ -0,0 +1,95 @@
+use intcomputer::{
+    asm::assemble,
+    days::day13::{bot, play, ArcadeError, Joystick, Moves, ParseMovesError, Tile},
+    Point,
+};
+
+/// Draws a block and a ball then lets the paddle move three times, the score is where the
+/// paddle ends up
+const GAME: &str = "
+        OUT #0
+        OUT #0
+        OUT #2
+        OUT #2
+        OUT #0
+        OUT #4
+    loop:
+        OUT [x]
+        OUT #1
+        OUT #3
+        IN -> [j]
+        OUT [x]
+        OUT #1
+        OUT #0
+        ADD [x], [j] -> [x]
+        ADD [turns], #-1 -> [turns]
+        JT [turns], loop
+        OUT [x]
+        OUT #1
+        OUT #3
+        OUT #-1
+        OUT #0
+        OUT [x]
+        HALT
+    x:     DATA 2
+    j:     DATA 0
+    turns: DATA 3
+";
+
+#[test]
+fn replays_recorded_moves() {
+    let moves: Moves = "<<\n>".parse().unwrap();
+    let mut replay = moves.0.clone().into_iter();
+
+    let game = play(&assemble(GAME).unwrap(), |_screen| replay.next()).unwrap();
+
+    assert!(game.finished);
+    assert_eq!(game.moves, moves);
+    assert_eq!(game.screen.score, 1);
+    assert_eq!(game.screen.paddle(), Some(Point::new(1, 1)));
+    assert_eq!(game.screen.tile(Point::new(0, 0)), Tile::Block);
+    assert_eq!(game.screen.to_string(), "= o\n - \nScore: 1");
+}
+
+#[test]
+fn bot_follows_the_ball() {
+    let game = play(&assemble(GAME).unwrap(), |screen| Some(bot(screen))).unwrap();
+
+    assert_eq!(game.moves.to_string(), "...");
+    assert_eq!(game.screen.score, 2);
+}
+
+#[test]
+fn stops_when_joystick_gives_up() {
+    let mut turns = 0;
+    let game = play(&assemble(GAME).unwrap(), |_screen| {
+        turns += 1;
+        (turns < 3).then_some(Joystick::Right)
+    })
+    .unwrap();
+
+    assert!(!game.finished);
+    assert_eq!(game.moves.to_string(), ">>");
+    assert_eq!(game.screen.blocks(), 1);
+    assert_eq!(game.screen.score, 0);
+}
+
+#[test]
+fn rejects_unknown_moves() {
+    assert_eq!("<.x>".parse::<Moves>(), Err(ParseMovesError('x')));
+}
+
+#[test]
+fn reports_bad_draw_instructions() {
+    let tile = assemble("OUT #0\nOUT #0\nOUT #7\nHALT").unwrap();
+    assert_eq!(
+        play(&tile, |_screen| None).unwrap_err(),
+        ArcadeError::UnknownTile(7)
+    );
+
+    let score = assemble("OUT #-1\nOUT #0\nOUT #-5\nHALT").unwrap();
+    assert_eq!(
+        play(&score, |_screen| None).unwrap_err(),
+        ArcadeError::NegativeScore(-5)
+    );
+}
@@ -10,10 +10,10 @@ fn first_input() {
 
     let mut computer = Computer::load(&tape, receiver, sender);
 
-    computer.run();
+    computer.run().unwrap();
 
     let final_tape = computer.dump_tape();
-    assert!(final_tape.starts_with(&vec![3500, 9, 10, 70, 2, 3, 11, 0, 99, 30, 40, 50]));
+    assert!(final_tape.starts_with(&[3500, 9, 10, 70, 2, 3, 11, 0, 99, 30, 40, 50]));
 }
 
 #[test]
@@ -24,10 +24,10 @@ fn third_input() {
 
     let mut computer = Computer::load(&tape, receiver, sender);
 
-    computer.run();
+    computer.run().unwrap();
 
     let final_tape = computer.dump_tape();
-    assert!(final_tape.starts_with(&vec![2, 3, 0, 6, 99]));
+    assert!(final_tape.starts_with(&[2, 3, 0, 6, 99]));
 }
 
 #[test]
@@ -38,10 +38,10 @@ fn fourth_input() {
 
     let mut computer = Computer::load(&tape, receiver, sender);
 
-    computer.run();
+    computer.run().unwrap();
 
     let final_tape = computer.dump_tape();
-    assert!(final_tape.starts_with(&vec![2, 4, 4, 5, 99, 9801]));
+    assert!(final_tape.starts_with(&[2, 4, 4, 5, 99, 9801]));
 }
 
 #[test]
@@ -52,8 +52,8 @@ fn fifth_input() {
 
     let mut computer = Computer::load(&tape, receiver, sender);
 
-    computer.run();
+    computer.run().unwrap();
 
     let final_tape = computer.dump_tape();
-    assert!(final_tape.starts_with(&vec![30, 1, 1, 4, 2, 5, 6, 0, 99]));
+    assert!(final_tape.starts_with(&[30, 1, 1, 4, 2, 5, 6, 0, 99]));
 }
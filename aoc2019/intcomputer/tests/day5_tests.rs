@@ -12,7 +12,7 @@ fn beefy_example_input_below_8() {
     let mut computer = Computer::load(&tape, in_receiver, out_sender.clone());
 
     in_sender.send(3).unwrap();
-    computer.run();
+    computer.run().unwrap();
 
     assert_eq!(out_receiver.recv().unwrap(), 999);
 }
@@ -27,7 +27,7 @@ fn beefy_example_input_equals_8() {
     let mut computer = Computer::load(&tape, in_receiver, out_sender.clone());
 
     in_sender.send(8).unwrap();
-    computer.run();
+    computer.run().unwrap();
 
     assert_eq!(out_receiver.recv().unwrap(), 1000);
 }
@@ -42,7 +42,7 @@ fn beefy_example_input_greater_than_8() {
     let mut computer = Computer::load(&tape, in_receiver, out_sender.clone());
 
     in_sender.send(19).unwrap();
-    computer.run();
+    computer.run().unwrap();
 
     assert_eq!(out_receiver.recv().unwrap(), 1001);
 }
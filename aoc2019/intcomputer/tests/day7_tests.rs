@@ -0,0 +1,124 @@
+use intcomputer::{
+    computer::{parse_tape, ComputerError},
+    days::day7::{self, Amplifiers, Best, DuplicatePhase},
+    network::NetworkError,
+};
+
+#[test]
+fn part1_examples() {
+    assert_eq!(
+        day7::part1("3,15,3,16,1002,16,10,16,1,16,15,15,4,15,99,0,0"),
+        Ok(43210)
+    );
+    assert_eq!(
+        day7::part1("3,23,3,24,1002,24,10,24,1002,23,-1,23,101,5,23,23,1,24,23,23,4,23,99,0,0"),
+        Ok(54321)
+    );
+}
+
+#[test]
+fn part2_examples() {
+    assert_eq!(
+        day7::part2(
+            "3,26,1001,26,-4,26,3,27,1002,27,2,27,1,27,26,27,4,27,1001,28,-1,28,1005,28,6,99,0,0,5"
+        ),
+        Ok(139629729)
+    );
+    assert_eq!(
+        day7::part2("3,52,1001,52,-5,52,3,53,1,52,56,54,1007,54,5,55,1005,55,26,1001,54,-5,54,1105,1,12,1,53,54,53,1008,54,0,55,1001,55,1,55,2,53,55,53,4,53,1001,56,-1,56,1005,56,6,99,0,0,0,0,10"),
+        Ok(18216)
+    );
+}
+
+#[test]
+fn searches_other_circuit_sizes() {
+    // Each amplifier outputs its input times ten plus its phase
+    let tape = parse_tape("3,15,3,16,1002,16,10,16,1,16,15,15,4,15,99,0,0");
+
+    for threads in [1, 8] {
+        let best = Amplifiers::new(3, 0..5)
+            .unwrap()
+            .threads(threads)
+            .search(&tape);
+        assert_eq!(
+            best,
+            Ok(Some(Best {
+                phase_settings: vec![4, 3, 2],
+                signal: 432
+            }))
+        );
+    }
+    assert_eq!(Amplifiers::new(6, 0..5).unwrap().search(&tape), Ok(None));
+}
+
+#[test]
+fn finds_best_feedback_phases() {
+    let tape = parse_tape(
+        "3,26,1001,26,-4,26,3,27,1002,27,2,27,1,27,26,27,4,27,1001,28,-1,28,1005,28,6,99,0,0,5",
+    );
+
+    let best = Amplifiers::new(5, 5..10)
+        .unwrap()
+        .feedback()
+        .search(&tape)
+        .unwrap();
+
+    assert_eq!(best.map(|b| b.phase_settings), Some(vec![9, 8, 7, 6, 5]));
+}
+
+#[test]
+fn ties_go_to_the_smallest_phase_settings() {
+    // Ignores both inputs and always outputs 7
+    let tape = parse_tape("3,7,3,8,104,7,99,0,0");
+
+    for _ in 0..10 {
+        let best = Amplifiers::new(3, [4, 2, 9, 1])
+            .unwrap()
+            .threads(4)
+            .search(&tape);
+        assert_eq!(best.unwrap().map(|b| b.phase_settings), Some(vec![1, 2, 4]));
+    }
+}
+
+#[test]
+fn reports_errors_from_any_thread() {
+    let tape = parse_tape("3,7,3,8,77,7,99,0,0");
+
+    let result = Amplifiers::new(4, 0..8).unwrap().threads(4).search(&tape);
+
+    assert!(matches!(
+        result,
+        Err(NetworkError::Computer { node: 0, .. })
+    ));
+}
+
+#[test]
+fn reports_the_first_failing_ordering() {
+    // Runs its phase as an opcode, so every ordering fails with its own first phase
+    let tape = parse_tape("3,4,3,5,0,0");
+
+    for _ in 0..10 {
+        let result = Amplifiers::new(2, [53, 51, 57, 52])
+            .unwrap()
+            .threads(4)
+            .search(&tape);
+        assert_eq!(
+            result,
+            Err(NetworkError::Computer {
+                node: 0,
+                error: ComputerError::InvalidOpcode {
+                    address: 4,
+                    opcode: 53
+                }
+            })
+        );
+    }
+}
+
+#[test]
+fn rejects_duplicate_phases() {
+    assert_eq!(
+        Amplifiers::new(2, [1, 2, 1]).unwrap_err(),
+        DuplicatePhase(1)
+    );
+}
@@ -10,7 +10,7 @@ fn example_that_produces_self() {
 
     let mut computer = Computer::load(&tape, receiver, sender);
 
-    computer.run();
+    computer.run().unwrap();
 
     assert!(computer.dump_tape().starts_with(&tape));
 }
@@ -23,7 +23,7 @@ fn example_that_makes_large_middle_num() {
     let (out_sender, out_receiver) = mpsc::channel();
 
     let mut computer = Computer::load(&tape, in_receiver, out_sender);
-    computer.run();
+    computer.run().unwrap();
 
     assert_eq!(out_receiver.recv().expect("no output"), tape[1]); 
 }
@@ -36,7 +36,7 @@ fn example_that_outputs_16dig_number() {
     let (out_sender, out_receiver) = mpsc::channel();
 
     let mut computer = Computer::load(&tape, in_receiver, out_sender);
-    computer.run();
+    computer.run().unwrap();
 
     assert!(out_receiver.recv().expect("no output") > 999999999999999); 
 }
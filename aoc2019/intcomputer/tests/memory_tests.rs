@@ -0,0 +1,32 @@
+use std::sync::mpsc;
+
+use intcomputer::computer::{parse_tape, Computer};
+
+#[test]
+fn loads_tape_bigger_than_old_limit() {
+    let mut tape = vec![0; 20_000];
+    tape[..4].copy_from_slice(&[1101, 2, 3, 19_999]);
+    tape[4] = 99;
+    let (sender, receiver) = mpsc::channel();
+
+    let mut computer = Computer::load(&tape, receiver, sender);
+    computer.run().unwrap();
+
+    assert_eq!(computer.dump_tape()[19_999], 5);
+}
+
+#[test]
+fn writes_far_beyond_tape() {
+    // Move the relative base far away then write 3 + 4 there and read it back out
+    let input = "109,50000,21101,3,4,0,204,0,99";
+    let tape = parse_tape(input);
+    let (_in_sender, in_receiver) = mpsc::channel();
+    let (out_sender, out_receiver) = mpsc::channel();
+
+    let mut computer = Computer::load(&tape, in_receiver, out_sender);
+    computer.run().unwrap();
+
+    assert_eq!(out_receiver.recv().expect("no output"), 7);
+    assert_eq!(computer.dump_tape().len(), 50_001);
+    assert_eq!(computer.memory().read(50_000), 7);
+}
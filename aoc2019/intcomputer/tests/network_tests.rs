@@ -0,0 +1,78 @@
+use intcomputer::{
+    asm::assemble,
+    computer::Computer,
+    network::{Circuit, NatEvent, NetworkError, Packet, PacketNetwork},
+};
+
+/// Node 1 sends (3, 4) to the NAT on boot, then every node forwards what it gets to the NAT
+const FORWARDER: &str = "
+        IN -> [address]
+        EQ [address], #1 -> [t]
+        JF [t], loop
+        OUT #255
+        OUT #3
+        OUT #4
+    loop:
+        IN -> [x]
+        EQ [x], #-1 -> [t]
+        JT [t], loop
+        IN -> [y]
+        OUT #255
+        OUT [x]
+        OUT [y]
+        JT #1, loop
+    address: DATA 0
+    x:       DATA 0
+    y:       DATA 0
+    t:       DATA 0
+";
+
+#[test]
+fn nat_receives_and_wakes() {
+    let tape = assemble(FORWARDER).unwrap();
+
+    let mut network = PacketNetwork::new(&tape, 3);
+    let first = network.run(|event| match event {
+        NatEvent::Received(packet) => Some(packet),
+        NatEvent::Sent(_) => None,
+    });
+    assert_eq!(first, Ok(Packet { x: 3, y: 4 }));
+
+    let mut network = PacketNetwork::new(&tape, 3);
+    let mut last_sent = None;
+    let repeated = network.run(|event| match event {
+        NatEvent::Sent(packet) if last_sent == Some(packet.y) => Some(packet.y),
+        NatEvent::Sent(packet) => {
+            last_sent = Some(packet.y);
+            None
+        }
+        NatEvent::Received(_) => None,
+    });
+    assert_eq!(repeated, Ok(4));
+}
+
+#[test]
+fn pipeline_doubles_down_the_line() {
+    let doubler = assemble("IN -> [9]\nMULT [9], #2 -> [9]\nOUT [9]\nHALT").unwrap();
+    let nodes = vec![Computer::new(&doubler); 3];
+
+    let mut circuit = Circuit::pipeline(nodes);
+    circuit.push_input(0, 5);
+
+    assert_eq!(circuit.run(), Ok(vec![40]));
+    assert_eq!(circuit.last_output(1), Some(20));
+}
+
+#[test]
+fn detects_deadlock() {
+    // Both wait for input before they output anything
+    let echo = assemble("IN -> [5]\nOUT [5]\nHALT").unwrap();
+    let mut circuit = Circuit::feedback(vec![Computer::new(&echo), Computer::new(&echo)]);
+
+    assert_eq!(
+        circuit.run(),
+        Err(NetworkError::Deadlock {
+            waiting: vec![0, 1]
+        })
+    );
+}
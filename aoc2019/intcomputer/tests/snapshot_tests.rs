@@ -0,0 +1,64 @@
+use intcomputer::{
+    asm::assemble,
+    computer::{Computer, RunState},
+    state::ComputerState,
+};
+
+/// Keeps a running total of its inputs and outputs it after each one
+const ACCUMULATOR: &str = "
+    loop:
+        IN -> [next]
+        ADD [total], [next] -> [total]
+        OUT [total]
+        JT #1, loop
+    next:  DATA 0
+    total: DATA 0
+";
+
+#[test]
+fn clones_explore_separate_branches() {
+    let mut computer = Computer::new(&assemble(ACCUMULATOR).unwrap());
+    computer.push_input(10);
+    assert_eq!(computer.run_until_io(), Ok(RunState::Output(10)));
+
+    let mut fork = computer.clone();
+    computer.push_input(1);
+    fork.push_input(100);
+
+    assert_eq!(computer.run_until_io(), Ok(RunState::Output(11)));
+    assert_eq!(fork.run_until_io(), Ok(RunState::Output(110)));
+}
+
+#[test]
+fn restore_rewinds() {
+    let mut computer = Computer::new(&assemble(ACCUMULATOR).unwrap());
+    computer.push_inputs([5, 6]);
+    assert_eq!(computer.run_until_io(), Ok(RunState::Output(5)));
+
+    let snapshot = computer.snapshot();
+    assert_eq!(snapshot.inputs, [6]);
+    assert_eq!(computer.run_until_io(), Ok(RunState::Output(11)));
+
+    computer.restore(&snapshot);
+    assert_eq!(computer.run_until_io(), Ok(RunState::Output(11)));
+    assert_eq!(computer.run_until_io(), Ok(RunState::NeedsInput));
+}
+
+#[test]
+fn saves_and_loads() {
+    let mut computer = Computer::new(&assemble(ACCUMULATOR).unwrap());
+    computer.write_memory(usize::MAX / 2, 42);
+    computer.push_inputs([3, 4]);
+    computer.run_until_io().unwrap();
+
+    let path = std::env::temp_dir().join(format!("intcomputer-{}.json", std::process::id()));
+    computer.snapshot().save(&path).unwrap();
+    let loaded = ComputerState::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(loaded, computer.snapshot());
+
+    let mut restored = Computer::from(&loaded);
+    assert_eq!(restored.memory().read(usize::MAX / 2), 42);
+    assert_eq!(restored.run_until_io(), Ok(RunState::Output(7)));
+}
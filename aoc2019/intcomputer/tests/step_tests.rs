@@ -0,0 +1,63 @@
+use intcomputer::computer::{parse_tape, Computer, RunState};
+
+#[test]
+fn waits_for_input_then_resumes() {
+    let input = "3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,1106,0,36,98,0,0,1002,21,125,20,4,20,1105,1,46,104,999,1105,1,46,1101,1000,1,20,4,20,1105,1,46,98,99";
+    let tape = parse_tape(input);
+
+    let mut computer = Computer::new(&tape);
+
+    assert_eq!(computer.run_until_io(), Ok(RunState::NeedsInput));
+    assert_eq!(computer.run_until_io(), Ok(RunState::NeedsInput));
+
+    computer.push_input(8);
+    assert_eq!(computer.run_until_io(), Ok(RunState::Output(1000)));
+    assert_eq!(computer.run_until_io(), Ok(RunState::Halted));
+    assert_eq!(computer.run_until_io(), Ok(RunState::Halted));
+}
+
+#[test]
+fn step_runs_one_instruction() {
+    let tape = parse_tape("1101,2,3,5,99,0");
+    let mut computer = Computer::new(&tape);
+
+    assert_eq!(computer.step(), Ok(None));
+    assert_eq!(computer.dump_tape()[5], 5);
+    assert_eq!(computer.step(), Ok(Some(RunState::Halted)));
+}
+
+#[test]
+fn run_to_halt_collects_outputs() {
+    let tape = parse_tape("109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99");
+    let mut computer = Computer::new(&tape);
+
+    assert_eq!(computer.run_to_halt(), Ok(tape));
+}
+
+#[test]
+fn drives_two_machines_on_one_thread() {
+    // Adds one to its input and outputs it, ten times
+    let tape = parse_tape("3,20,1001,20,1,20,4,20,1001,21,1,21,1008,21,10,22,1006,22,0,99,0,0,0");
+    let mut ping = Computer::new(&tape);
+    let mut pong = Computer::new(&tape);
+
+    ping.push_input(0);
+    let mut last = 0;
+    loop {
+        match ping.run_until_io().unwrap() {
+            RunState::Output(value) => pong.push_input(value),
+            RunState::Halted => break,
+            RunState::NeedsInput => {}
+        }
+        match pong.run_until_io().unwrap() {
+            RunState::Output(value) => {
+                last = value;
+                ping.push_input(value);
+            }
+            RunState::Halted => break,
+            RunState::NeedsInput => {}
+        }
+    }
+
+    assert_eq!(last, 20);
+}
@@ -0,0 +1,71 @@
+use std::{fs::File, io::BufRead, io::BufReader, sync::mpsc};
+
+use intcomputer::{
+    asm::assemble,
+    computer::{Computer, RunState},
+    trace::{TraceRecord, Tracer},
+};
+
+const COUNTDOWN: &str = "
+    loop:
+        ADD [n], #-1 -> [n]
+        JT [n], loop
+        HALT
+    n: DATA 3
+";
+
+#[test]
+fn writes_jsonl_trace() {
+    let path = std::env::temp_dir().join(format!("intcomputer-{}.jsonl", std::process::id()));
+    let mut tracer = Tracer::jsonl(File::create(&path).unwrap());
+
+    let mut computer = Computer::new(&assemble(COUNTDOWN).unwrap());
+    assert_eq!(
+        computer.run_until_io_traced(&mut tracer),
+        Ok(RunState::Halted)
+    );
+    let profile = tracer.finish().unwrap();
+
+    let records: Vec<TraceRecord> = BufReader::new(File::open(&path).unwrap())
+        .lines()
+        .map(|line| serde_json::from_str(&line.unwrap()).unwrap())
+        .collect();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(records.len(), 6);
+    assert_eq!(
+        records[0],
+        TraceRecord {
+            step: 0,
+            address: 0,
+            opcode: 1001,
+            instruction: "ADD [8], #-1 -> [8]".to_string(),
+            operands: vec![3, -1, 8],
+            writes: vec![(8, 2)],
+        }
+    );
+    assert_eq!(records[5].step, 5);
+    assert_eq!(records[5].instruction, "JT [8], #0");
+
+    assert_eq!(profile.total, 6);
+    assert_eq!(profile.per_opcode.get(&1), Some(&3));
+    assert_eq!(profile.per_opcode.get(&5), Some(&3));
+    assert_eq!(profile.hottest(), [(0, 3), (4, 3)]);
+}
+
+#[test]
+fn profiles_channel_runs() {
+    let tape = assemble("IN -> [5]\nOUT [5]\nHALT").unwrap();
+    let (in_sender, in_receiver) = mpsc::channel();
+    let (out_sender, out_receiver) = mpsc::channel();
+    in_sender.send(9).unwrap();
+
+    let mut tracer = Tracer::profile_only();
+    let mut computer = Computer::load(&tape, in_receiver, out_sender);
+    computer.run_traced(&mut tracer).unwrap();
+
+    assert_eq!(out_receiver.recv(), Ok(9));
+    let profile = tracer.finish().unwrap();
+    assert_eq!(profile.total, 2);
+    assert!(profile.to_string().starts_with("2 instructions executed"));
+}
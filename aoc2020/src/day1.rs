@@ -1,30 +1,27 @@
-use std::time::Instant;
+use aoc_solution::{puzzle, Solution};
 
-fn main() {
-    let mut numbers: [i32; 200] = [0; 200];
+pub struct Day1;
 
-    let input = include_str!("../../inputs/001.txt");
+impl Solution for Day1 {
+    fn part1(&self, input: &str) -> String {
+        let (a, b) = find_pair(&parse_numbers(input));
+        (a * b).to_string()
+    }
 
-    input
-        .lines()
-        .enumerate()
-        .for_each(|(i, x)| numbers[i] = x.parse().unwrap());
+    fn part2(&self, input: &str) -> Option<String> {
+        let (a, b, c) = find_triple(&parse_numbers(input));
+        Some((a * b * c).to_string())
+    }
+}
 
-    numbers.sort_unstable();
+puzzle!(2020, 1, Day1, "inputs/001.txt");
 
-    // Part 1
-    let start = Instant::now();
-    let (a, b) = find_pair(&numbers);
-    let duration = start.elapsed();
-    println!("a: {}, b: {}, ab: {}", a, b, a * b);
-    println!("Time taken: {:?}", duration);
+fn parse_numbers(input: &str) -> Vec<i32> {
+    let mut numbers: Vec<i32> = input.lines().map(|x| x.parse().unwrap()).collect();
 
-    // Part 2
-    let start = Instant::now();
-    let (a, b, c) = find_triple(&numbers);
-    let duration = start.elapsed();
-    println!("a: {}, b: {}, c: {}, abc: {}", a, b, c, a * b * c);
-    println!("Time taken: {:?}", duration);
+    numbers.sort_unstable();
+
+    numbers
 }
 
 fn find_pair(numbers: &[i32]) -> (i32, i32) {
@@ -1,24 +1,30 @@
-use std::{collections::HashMap, time::Instant};
+use std::collections::HashMap;
 
-fn main() {
-    let mut numbers: Vec<usize> = include_str!("../../inputs/010.txt")
-        .lines()
-        .map(|line| line.parse().unwrap())
-        .collect();
+use aoc_solution::{puzzle, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    fn part1(&self, input: &str) -> String {
+        let answer = get_split(&parse_adapters(input));
+        (answer.0 * answer.1).to_string()
+    }
+
+    fn part2(&self, input: &str) -> Option<String> {
+        Some(find_routes(&parse_adapters(input)).to_string())
+    }
+}
+
+puzzle!(2020, 10, Day10, "inputs/010.txt");
+
+/// The adapters in order with the outlet and the device on either end
+fn parse_adapters(input: &str) -> Vec<usize> {
+    let mut numbers: Vec<usize> = input.lines().map(|line| line.parse().unwrap()).collect();
     numbers.push(0);
     numbers.sort_unstable();
     numbers.push(numbers.last().unwrap() + 3);
 
-    let start = Instant::now();
-    let answer = get_split(&numbers);
-
-    println!("Part one: {}", answer.0 * answer.1);
-    println!("Time taken: {:#?}", start.elapsed());
-
-    let start = Instant::now();
-    let answer = find_routes(&numbers);
-    println!("Part two: {}", answer);
-    println!("Time taken: {:#?}", start.elapsed());
+    numbers
 }
 
 fn get_split(numbers: &[usize]) -> (usize, usize) {
@@ -42,9 +48,8 @@ fn find_routes(numbers: &[usize]) -> usize {
 
     paths.insert(numbers.last().copied().unwrap(), 1);
 
-    for i in (0..(numbers.len() - 1)).into_iter().rev() {
+    for i in (0..(numbers.len() - 1)).rev() {
         let neighbours = ((i + 1)..=std::cmp::min(i + 3, numbers.len() - 1))
-            .into_iter()
             .filter_map(|j| {
                 if (1..=3).contains(&(numbers[j] - numbers[i])) {
                     Some(paths.get(&numbers[j]).unwrap())
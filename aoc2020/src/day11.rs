@@ -1,19 +1,19 @@
-use std::time::Instant;
+use aoc_solution::{puzzle, Solution};
 
-fn main() {
-    let input = include_str!("../../inputs/011.txt");
+pub struct Day11;
 
-    let start = Instant::now();
+impl Solution for Day11 {
+    fn part1(&self, input: &str) -> String {
+        part_one(input).to_string()
+    }
 
-    println!("Part one: {}", part_one(&input));
-    println!("Time taken: {:#?}", start.elapsed());
-
-    let start = Instant::now();
-
-    println!("Part two: {}", part_two(&input));
-    println!("Time taken: {:#?}", start.elapsed());
+    fn part2(&self, input: &str) -> Option<String> {
+        Some(part_two(input).to_string())
+    }
 }
 
+puzzle!(2020, 11, Day11, "inputs/011.txt");
+
 fn part_one(input: &str) -> usize {
     let rows: isize = input.lines().count() as isize;
     let cols: isize = input.lines().next().map(|line| line.len()).unwrap() as isize;
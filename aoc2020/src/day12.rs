@@ -1,47 +1,55 @@
-fn main() {
-    let input = include_str!("../../inputs/012.txt");
-
-    let mut ship = Ship::default();
-
-    input.lines().for_each(|line| {
-        let instruction: char = line.as_bytes()[0] as char;
-        let amount: usize = atoi::atoi(&line.as_bytes()[1..]).unwrap();
-
-        match instruction {
-            'R' => ship.turn_ship(true, amount),
-            'L' => ship.turn_ship(false, amount),
-            'N' => ship.move_ship(Bearing::North, amount),
-            'E' => ship.move_ship(Bearing::East, amount),
-            'S' => ship.move_ship(Bearing::South, amount),
-            'W' => ship.move_ship(Bearing::West, amount),
-            'F' => ship.move_forward(amount),
-            _ => panic!("bad instruction, {}", instruction),
-        }
-    });
-
-    println!("Part one: {}", ship.manhattan());
-
-    let mut waypoint_ship = WaypointShip::default();
+use aoc_solution::{puzzle, Solution};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    fn part1(&self, input: &str) -> String {
+        let mut ship = Ship::default();
+
+        input.lines().for_each(|line| {
+            let instruction: char = line.as_bytes()[0] as char;
+            let amount: usize = atoi::atoi(&line.as_bytes()[1..]).unwrap();
+
+            match instruction {
+                'R' => ship.turn_ship(true, amount),
+                'L' => ship.turn_ship(false, amount),
+                'N' => ship.move_ship(Bearing::North, amount),
+                'E' => ship.move_ship(Bearing::East, amount),
+                'S' => ship.move_ship(Bearing::South, amount),
+                'W' => ship.move_ship(Bearing::West, amount),
+                'F' => ship.move_forward(amount),
+                _ => panic!("bad instruction, {}", instruction),
+            }
+        });
 
-    input.lines().for_each(|line| {
-        let instruction: char = line.as_bytes()[0] as char;
-        let amount: usize = atoi::atoi(&line.as_bytes()[1..]).unwrap();
+        ship.manhattan().to_string()
+    }
 
-        match instruction {
-            'R' => waypoint_ship.rotate_waypoint(true, amount),
-            'L' => waypoint_ship.rotate_waypoint(false, amount),
-            'N' => waypoint_ship.move_waypoint(Bearing::North, amount),
-            'E' => waypoint_ship.move_waypoint(Bearing::East, amount),
-            'S' => waypoint_ship.move_waypoint(Bearing::South, amount),
-            'W' => waypoint_ship.move_waypoint(Bearing::West, amount),
-            'F' => waypoint_ship.move_to_waypoint(amount),
-            _ => panic!("bad instruction, {}", instruction),
-        }
-    });
+    fn part2(&self, input: &str) -> Option<String> {
+        let mut waypoint_ship = WaypointShip::default();
+
+        input.lines().for_each(|line| {
+            let instruction: char = line.as_bytes()[0] as char;
+            let amount: usize = atoi::atoi(&line.as_bytes()[1..]).unwrap();
+
+            match instruction {
+                'R' => waypoint_ship.rotate_waypoint(true, amount),
+                'L' => waypoint_ship.rotate_waypoint(false, amount),
+                'N' => waypoint_ship.move_waypoint(Bearing::North, amount),
+                'E' => waypoint_ship.move_waypoint(Bearing::East, amount),
+                'S' => waypoint_ship.move_waypoint(Bearing::South, amount),
+                'W' => waypoint_ship.move_waypoint(Bearing::West, amount),
+                'F' => waypoint_ship.move_to_waypoint(amount),
+                _ => panic!("bad instruction, {}", instruction),
+            }
+        });
 
-    println!("Part two: {}", waypoint_ship.manhattan());
+        Some(waypoint_ship.manhattan().to_string())
+    }
 }
 
+puzzle!(2020, 12, Day12, "inputs/012.txt");
+
 #[derive(Debug)]
 enum Bearing {
     North,
@@ -144,7 +152,7 @@ impl WaypointShip {
 
     fn rotate_waypoint(&mut self, clockwise: bool, amount: usize) {
         let rotation = match clockwise {
-            true => (amount as f32 * -1.0).to_radians(),
+            true => (-(amount as f32)).to_radians(),
             false => (amount as f32).to_radians(),
         };
 
@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+
+use aoc_solution::{puzzle, Solution};
+use santas_little_helpers::math::crt;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    fn part1(&self, input: &str) -> String {
+        let input: Vec<&str> = input.lines().collect();
+        part_one_faster(&input).to_string()
+    }
+
+    fn part2(&self, input: &str) -> Option<String> {
+        let input: Vec<&str> = input.lines().collect();
+        Some(part_two(&input).to_string())
+    }
+}
+
+puzzle!(2020, 13, Day13, "inputs/013.txt");
+
+/// The first go at part one, stepping through every departure
+pub fn part_one(input: &[&str]) -> usize {
+    let earliest_departure = input[0].parse().unwrap();
+    let bus_times: HashMap<usize, usize> = input[1]
+        .split(',')
+        .filter(|bus_time| bus_time != &"x")
+        .map(|bus_time| bus_time.parse::<usize>().unwrap())
+        .map(|bus_time| {
+            let mut next_bus = bus_time;
+            while next_bus < earliest_departure {
+                next_bus += bus_time;
+            }
+            (bus_time, next_bus)
+        })
+        .collect();
+
+    let min = bus_times.iter().min_by(|a, b| a.1.cmp(b.1)).unwrap();
+
+    min.0 * (min.1 - earliest_departure)
+}
+
+fn part_one_faster(input: &[&str]) -> usize {
+    let earliest_departure: usize = input[0].parse().unwrap();
+    let answer = input[1]
+        .split(',')
+        .filter(|bus_time| bus_time != &"x")
+        .filter_map(|bus_time| bus_time.parse::<usize>().ok())
+        .map(|bus_time| (bus_time, bus_time - (earliest_departure % bus_time)))
+        .min_by_key(|x| x.1)
+        .unwrap();
+
+    answer.0 * answer.1
+}
+
+fn part_two(input: &[&str]) -> i64 {
+    // bus i leaves i minutes after the timestamp so the timestamp is -i (mod bus)
+    let congruences = input[1]
+        .split(',')
+        .enumerate()
+        .filter(|(_, bus)| bus != &"x")
+        .map(|(i, bus)| (-(i as i64), bus.parse::<i64>().unwrap()));
+
+    crt(congruences).unwrap().0
+}
+
+#[test]
+fn test_part_two() {
+    assert_eq!(part_two(&["939", "7,13,x,x,59,x,31,19"]), 1068781);
+    assert_eq!(part_two(&["0", "1789,37,47,1889"]), 1202161486);
+}
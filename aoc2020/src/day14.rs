@@ -1,5 +1,6 @@
-use std::{collections::HashMap, time::Instant};
+use std::collections::HashMap;
 
+use aoc_solution::{puzzle, Solution};
 use regex::Regex;
 
 struct Mask {
@@ -7,16 +8,20 @@ struct Mask {
     clear: usize,
 }
 
-fn main() {
-    let input = include_str!("../../inputs/014.txt");
+pub struct Day14;
 
-    let start = Instant::now();
-    println!("Part one: {} in {:#?}", part_one(input), start.elapsed());
+impl Solution for Day14 {
+    fn part1(&self, input: &str) -> String {
+        part_one(input).to_string()
+    }
 
-    let start = Instant::now();
-    println!("Part two: {} in {:#?}", part_two(input), start.elapsed());
+    fn part2(&self, input: &str) -> Option<String> {
+        Some(part_two(input).to_string())
+    }
 }
 
+puzzle!(2020, 14, Day14, "inputs/014.txt");
+
 fn part_one(input: &str) -> usize {
     let mut memory: HashMap<usize, usize> = HashMap::new();
 
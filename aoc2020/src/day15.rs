@@ -1,18 +1,21 @@
-use std::{
-    collections::{hash_map::Entry, HashMap},
-    time::Instant,
-};
+use std::collections::{hash_map::Entry, HashMap};
 
-fn main() {
-    let input = include_str!("../../inputs/015.txt");
+use aoc_solution::{puzzle, Solution};
 
-    let start = Instant::now();
-    println!("Part one: {} in {:#?}", part_one(input), start.elapsed());
+pub struct Day15;
 
-    let start = Instant::now();
-    println!("Part two: {} in {:#?}", part_two(input), start.elapsed());
+impl Solution for Day15 {
+    fn part1(&self, input: &str) -> String {
+        part_one(input).to_string()
+    }
+
+    fn part2(&self, input: &str) -> Option<String> {
+        Some(part_two(input).to_string())
+    }
 }
 
+puzzle!(2020, 15, Day15, "inputs/015.txt");
+
 fn part_one(input: &str) -> usize {
     let mut numbers = input
         .trim_end()
@@ -69,7 +72,8 @@ fn part_two(input: &str) -> u32 {
 
     const RATIO: u32 = 30_000_000 / 15;
     let mut sparse_numbers: HashMap<u32, u32> = HashMap::with_capacity(30_000_000 / 10000);
-    let mut dense_numbers: [u32; RATIO as usize] = [0; RATIO as usize];
+    // on the heap, the runner and test threads don't have a stack big enough to hold it
+    let mut dense_numbers: Vec<u32> = vec![0; RATIO as usize];
 
     input
         .iter()
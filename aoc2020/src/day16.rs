@@ -1,34 +1,29 @@
-use std::{
-    collections::{HashMap, HashSet},
-    time::Instant,
-};
+use std::collections::{HashMap, HashSet};
 
+use aoc_solution::{puzzle, Solution};
 use regex::Regex;
 
-fn main() {
-    let input = include_str!("../../inputs/016.txt");
+pub struct Day16;
 
-    let rules: Vec<Rule> = read_rules(input);
+impl Solution for Day16 {
+    fn part1(&self, input: &str) -> String {
+        let rules: Vec<Rule> = read_rules(input);
+        let nearby_tickets: Vec<_> = read_tickets("nearby tickets:", input).collect();
 
-    let my_ticket: Vec<usize> = read_tickets("your ticket:", input).next().unwrap();
-
-    let nearby_tickets: Vec<_> = read_tickets("nearby tickets:", input).collect();
+        part_one(&nearby_tickets, &rules).to_string()
+    }
 
-    let start = Instant::now();
-    println!(
-        "Part one: {} in {:#?}",
-        part_one(&nearby_tickets, &rules),
-        start.elapsed()
-    );
+    fn part2(&self, input: &str) -> Option<String> {
+        let rules: Vec<Rule> = read_rules(input);
+        let my_ticket: Vec<usize> = read_tickets("your ticket:", input).next().unwrap();
+        let nearby_tickets: Vec<_> = read_tickets("nearby tickets:", input).collect();
 
-    let start = Instant::now();
-    println!(
-        "Part two: {} in {:#?}",
-        part_two(&my_ticket, &nearby_tickets, &rules),
-        start.elapsed()
-    );
+        Some(part_two(&my_ticket, &nearby_tickets, &rules).to_string())
+    }
 }
 
+puzzle!(2020, 16, Day16, "inputs/016.txt");
+
 fn part_one(tickets: &[Vec<usize>], rules: &[Rule]) -> usize {
     tickets
         .iter()
@@ -120,7 +115,7 @@ fn part_two(my_ticket: &[usize], nearby_tickets: &[Vec<usize>], rules: &[Rule])
         .product()
 }
 
-fn read_rules(input: &str) -> Vec<Rule> {
+fn read_rules(input: &str) -> Vec<Rule<'_>> {
     let reg_rule = Regex::new(r#"^(.*): (\d+)-(\d+) or (\d+)-(\d+)$"#).unwrap();
 
     input
@@ -144,8 +139,8 @@ fn read_rules(input: &str) -> Vec<Rule> {
         .collect()
 }
 
-// header and input must both have lifetime of static because the returned iterator could live forever (static)
-fn read_tickets(header: &'static str, input: &'static str) -> impl Iterator<Item = Vec<usize>> {
+// header and input must both outlive the returned iterator
+fn read_tickets<'a>(header: &'a str, input: &'a str) -> impl Iterator<Item = Vec<usize>> + 'a {
     input
         .lines()
         .skip_while(move |&line| line != header)
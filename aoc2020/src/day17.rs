@@ -1,26 +1,27 @@
-use std::time::Instant;
-
+use aoc_solution::{puzzle, Solution};
 use itertools::Itertools;
 
-fn main() {
-    let input: Vec<Vec<bool>> = include_bytes!("../../inputs/017.txt")
+pub struct Day17;
+
+impl Solution for Day17 {
+    fn part1(&self, input: &str) -> String {
+        part_one(&parse_cubes(input), 6).to_string()
+    }
+
+    fn part2(&self, input: &str) -> Option<String> {
+        Some(part_two(&parse_cubes(input), 6).to_string())
+    }
+}
+
+puzzle!(2020, 17, Day17, "inputs/017.txt");
+
+fn parse_cubes(input: &str) -> Vec<Vec<bool>> {
+    input
+        .trim_end()
+        .as_bytes()
         .split(|&byte| byte == b'\n')
         .map(|line| { line.iter().map(|c| matches!(c, b'#')) }.collect())
-        .collect();
-
-    let start = Instant::now();
-    println!(
-        "Part one: {} in {:#?}",
-        part_one(&input, 6),
-        start.elapsed()
-    );
-
-    let start = Instant::now();
-    println!(
-        "Part two: {} in {:#?}",
-        part_two(&input, 6),
-        start.elapsed()
-    );
+        .collect()
 }
 
 fn part_one(input: &[Vec<bool>], cycles: usize) -> usize {
@@ -76,7 +77,7 @@ fn part_one(input: &[Vec<bool>], cycles: usize) -> usize {
                             (
                                 (x as isize + offset.0) as usize,
                                 (y as isize + offset.1) as usize,
-                                (z as isize + offset.2).abs() as usize,
+                                (z as isize + offset.2).unsigned_abs(),
                             )
                         })
                         .filter(|(x, y, z)| previous_state[*z][*y][*x])
@@ -150,8 +151,8 @@ fn part_two(input: &[Vec<bool>], cycles: usize) -> usize {
                                 (
                                     (x as isize + r.0) as usize,
                                     (y as isize + r.1) as usize,
-                                    (z as isize + r.2).abs() as usize,
-                                    (w as isize + r.3).abs() as usize,
+                                    (z as isize + r.2).unsigned_abs(),
+                                    (w as isize + r.3).unsigned_abs(),
                                 )
                             })
                             .filter(|(x, y, z, w)| previous_state[*w][*z][*y][*x])
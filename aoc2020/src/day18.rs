@@ -1,15 +1,19 @@
-use std::time::Instant;
+use aoc_solution::{puzzle, Solution};
 
-fn main() {
-    let input = include_str!("../../inputs/018.txt");
+pub struct Day18;
 
-    let start = Instant::now();
-    println!("Part one: {} in {:#?}", part_one(input), start.elapsed());
+impl Solution for Day18 {
+    fn part1(&self, input: &str) -> String {
+        part_one(input).to_string()
+    }
 
-    let start = Instant::now();
-    println!("Part two: {} in {:#?}", part_two(input), start.elapsed());
+    fn part2(&self, input: &str) -> Option<String> {
+        Some(part_two(input).to_string())
+    }
 }
 
+puzzle!(2020, 18, Day18, "inputs/018.txt");
+
 fn part_one(input: &str) -> usize {
     input
         .lines()
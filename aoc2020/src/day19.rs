@@ -1,8 +1,24 @@
-use std::{collections::HashMap, time::Instant};
+use std::collections::HashMap;
 
-fn main() {
-    let input = include_str!("../../inputs/019.txt");
+use aoc_solution::{puzzle, Solution};
 
+pub struct Day19;
+
+impl Solution for Day19 {
+    fn part1(&self, input: &str) -> String {
+        let (rules, strings) = parse_input(input);
+        part_one(&rules, &strings).to_string()
+    }
+
+    fn part2(&self, input: &str) -> Option<String> {
+        let (rules, strings) = parse_input(input);
+        Some(part_two(&rules, &strings).to_string())
+    }
+}
+
+puzzle!(2020, 19, Day19, "inputs/019.txt");
+
+fn parse_input(input: &str) -> (HashMap<usize, Rule>, Vec<&str>) {
     let rules: HashMap<usize, Rule> = input
         .lines()
         .take_while(|line| !line.trim().is_empty())
@@ -17,19 +33,7 @@ fn main() {
         .skip_while(|line| !line.trim().is_empty())
         .collect();
 
-    let start = Instant::now();
-    println!(
-        "Part one: {} in {:#?}",
-        part_one(&rules, &strings),
-        start.elapsed()
-    );
-
-    let start = Instant::now();
-    println!(
-        "Part two: {} in {:#?}",
-        part_two(&rules, &strings),
-        start.elapsed()
-    );
+    (rules, strings)
 }
 
 fn part_one(rules: &HashMap<usize, Rule>, strings: &[&str]) -> usize {
@@ -141,7 +145,7 @@ fn works_for_31(limit: usize, string: &[u8], rules: &HashMap<usize, Rule>) -> bo
     (0..limit)
         .try_fold(string, |string, _| {
             match matches_consumes(string, 31, rules) {
-                Some(string) if string.is_empty() => Err(true),
+                Some([]) => Err(true),
                 Some(string) => Ok(string),
                 None => Err(false),
             }
@@ -1,3 +1,5 @@
+use aoc_solution::{puzzle, Solution};
+
 struct PasswordEntry {
     min: usize,
     max: usize,
@@ -7,7 +9,7 @@ struct PasswordEntry {
 
 impl PasswordEntry {
     fn new(line: &str) -> PasswordEntry {
-        let pieces: Vec<&str> = line.split(|c| c == '-' || c == ' ').collect();
+        let pieces: Vec<&str> = line.split(['-', ' ']).collect();
         let min: usize = pieces[0].parse().unwrap();
         let max: usize = pieces[1].parse().unwrap();
         let letter = pieces[2].chars().next().unwrap();
@@ -22,30 +24,30 @@ impl PasswordEntry {
     }
 }
 
-fn main() {
-    let input = include_str!("../../inputs/002.txt");
-
-    let entries: Vec<PasswordEntry> = input.lines().map(|line| PasswordEntry::new(line)).collect();
+pub struct Day2;
 
-    // part one
-    println!("Part one");
-    println!(
-        "Count correct: {}",
-        entries
+impl Solution for Day2 {
+    fn part1(&self, input: &str) -> String {
+        parse_entries(input)
             .iter()
             .filter(|entry| is_pass_correct_part_one(entry))
             .count()
-    );
+            .to_string()
+    }
 
-    // part two
-    println!("\nPart two");
-    println!(
-        "Count correct: {}",
-        entries
+    fn part2(&self, input: &str) -> Option<String> {
+        let count = parse_entries(input)
             .iter()
             .filter(|entry| is_pass_correct_part_two(entry))
-            .count()
-    )
+            .count();
+        Some(count.to_string())
+    }
+}
+
+puzzle!(2020, 2, Day2, "inputs/002.txt");
+
+fn parse_entries(input: &str) -> Vec<PasswordEntry> {
+    input.lines().map(PasswordEntry::new).collect()
 }
 
 fn is_pass_correct_part_one(entry: &PasswordEntry) -> bool {
@@ -87,7 +89,7 @@ fn test_incorrect_password() {
     let letter = 'b';
     let password = "cdefg".to_string();
 
-    assert!(is_pass_correct_part_one(&PasswordEntry {
+    assert!(!is_pass_correct_part_one(&PasswordEntry {
         min,
         max,
         letter,
@@ -1,10 +1,12 @@
-use std::{
-    collections::{hash_map::Entry, HashMap, LinkedList},
-    time::Instant,
-};
+use std::collections::{hash_map::Entry, HashMap, LinkedList};
+
+use aoc_solution::{puzzle, Solution};
 
 use itertools::Itertools;
 
+/// From the puzzle, only the # matter
+const SEA_MONSTER: &str = "                  # \n#    ##    ##    ###\n #  #  #  #  #  #   ";
+
 struct TileEdges {
     id: usize,
     edges: [u16; 4],
@@ -28,16 +30,20 @@ impl Coord {
     }
 }
 
-fn main() {
-    let input = include_str!("../../inputs/020.txt");
+pub struct Day20;
 
-    let start = Instant::now();
-    println!("Part one: {} in {:#?}", part_one(input), start.elapsed());
+impl Solution for Day20 {
+    fn part1(&self, input: &str) -> String {
+        part_one(input.trim_end()).to_string()
+    }
 
-    let start = Instant::now();
-    println!("Part two: {} in {:#?}", part_two(input), start.elapsed());
+    fn part2(&self, input: &str) -> Option<String> {
+        Some(part_two(input.trim_end()).to_string())
+    }
 }
 
+puzzle!(2020, 20, Day20, "inputs/020.txt");
+
 fn part_one(input: &str) -> usize {
     let tiles = input
         .split("\n\n")
@@ -104,8 +110,7 @@ fn part_one(input: &str) -> usize {
 }
 
 fn part_two(input: &str) -> usize {
-    // chose to have the sea monster in its own file instead of trying to write it in by hand
-    let sea_monster: Vec<Vec<u8>> = include_str!("../../inputs/monster.txt")
+    let sea_monster: Vec<Vec<u8>> = SEA_MONSTER
         .split('\n')
         .map(|line| line.bytes().collect())
         .collect();
@@ -270,8 +275,8 @@ where
     let mut new_grid: Vec<Vec<T>> = Vec::with_capacity(original_size.0);
     for x in 0..original_size.0 {
         new_grid.push(Vec::with_capacity(original_size.1));
-        for y in 0..original_size.1 {
-            new_grid[x].push(grid[original_size.0 - x - 1][y]);
+        for &cell in &grid[original_size.0 - x - 1] {
+            new_grid[x].push(cell);
         }
     }
 
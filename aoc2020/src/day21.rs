@@ -1,28 +1,26 @@
 use std::{
     collections::{HashMap, HashSet},
     iter::FromIterator,
-    time::Instant,
 };
 
-fn main() {
-    let input = include_str!("../../inputs/021.txt");
-    let foods = parse_input(input);
+use aoc_solution::{puzzle, Solution};
 
-    let start = Instant::now();
-    let mapping = get_allergic_ingredients(&foods);
-    println!("Mapped allergens to ingredients in {:#?}", start.elapsed());
+pub struct Day21;
 
-    let start = Instant::now();
-    println!(
-        "Part one: {} in {:#?}",
-        part_one(&mapping, &foods),
-        start.elapsed()
-    );
+impl Solution for Day21 {
+    fn part1(&self, input: &str) -> String {
+        let foods = parse_input(input);
+        part_one(&get_allergic_ingredients(&foods), &foods).to_string()
+    }
 
-    let start = Instant::now();
-    println!("Part two: {} in {:#?}", part_two(&mapping), start.elapsed());
+    fn part2(&self, input: &str) -> Option<String> {
+        let foods = parse_input(input);
+        Some(part_two(&get_allergic_ingredients(&foods)))
+    }
 }
 
+puzzle!(2020, 21, Day21, "inputs/021.txt");
+
 fn part_one(allergen_mapping: &HashMap<&str, &str>, foods: &[Food]) -> usize {
     // get a list of all the ingredients that are allergens
     let allergen_ingredients: Vec<&str> = allergen_mapping.clone().into_values().collect();
@@ -55,7 +53,7 @@ struct Food<'a> {
     allergens: HashSet<&'a str>,
 }
 
-fn parse_input(input: &str) -> Vec<Food> {
+fn parse_input(input: &str) -> Vec<Food<'_>> {
     input
         .lines()
         .map(|line| {
@@ -93,15 +91,13 @@ fn get_allergic_ingredients<'a>(foods: &[Food<'a>]) -> HashMap<&'a str, &'a str>
     let mut allergen_mappings: HashMap<&str, &str> = HashMap::new();
 
     while !allergen_ingredients.is_empty() {
-        let to_remove: Option<(&str, &str)>;
-
         let allergen = allergen_ingredients
             .iter()
             .find(|allergen| allergen.1.len() == 1)
             .unwrap();
 
         let ingredient = Vec::from_iter(allergen.1)[0];
-        to_remove = Some((allergen.0, ingredient));
+        let to_remove: Option<(&str, &str)> = Some((allergen.0, ingredient));
         allergen_mappings.insert(allergen.0, *ingredient);
 
         if let Some(to_remove) = to_remove {
@@ -1,16 +1,31 @@
 use std::cmp::Ordering;
 use std::collections::{HashSet, VecDeque};
-use std::time::Instant;
+
+use aoc_solution::{puzzle, Solution};
 
 enum Winner {
     Player1,
     Player2,
 }
 
-fn main() {
-    let (deck1, deck2) = include_str!("../../inputs/022.txt")
-        .split_once("\n\n")
-        .unwrap();
+pub struct Day22;
+
+impl Solution for Day22 {
+    fn part1(&self, input: &str) -> String {
+        let (deck1, deck2) = parse_decks(input);
+        part_one((&deck1, &deck2)).to_string()
+    }
+
+    fn part2(&self, input: &str) -> Option<String> {
+        let (deck1, deck2) = parse_decks(input);
+        Some(part_two((&deck1, &deck2)).to_string())
+    }
+}
+
+puzzle!(2020, 22, Day22, "inputs/022.txt");
+
+fn parse_decks(input: &str) -> (VecDeque<usize>, VecDeque<usize>) {
+    let (deck1, deck2) = input.split_once("\n\n").unwrap();
 
     let deck1: VecDeque<usize> = deck1
         .lines()
@@ -23,19 +38,7 @@ fn main() {
         .map(|line| line.parse::<usize>().unwrap())
         .collect();
 
-    let start = Instant::now();
-    println!(
-        "Part one: {} in {:#?}",
-        part_one((&deck1, &deck2)),
-        start.elapsed()
-    );
-
-    let start = Instant::now();
-    println!(
-        "Part two: {} in {:#?}",
-        part_two((&deck1, &deck2)),
-        start.elapsed()
-    );
+    (deck1, deck2)
 }
 
 fn part_one(decks: (&VecDeque<usize>, &VecDeque<usize>)) -> usize {
@@ -1,19 +1,27 @@
-use std::{mem::MaybeUninit, time::Instant};
+use aoc_solution::{puzzle, Solution};
 
 const NUM_CUPS: usize = 9;
 
-fn main() {
-    let input: Vec<u8> = include_bytes!("../../inputs/023.txt")
-        .iter()
-        .filter(|&b| b != &b'\n')
-        .map(|&b| b - b'0')
-        .collect();
+pub struct Day23;
 
-    let start = Instant::now();
-    println!("Part one: {} in {:#?}", part_one(&input), start.elapsed());
+impl Solution for Day23 {
+    fn part1(&self, input: &str) -> String {
+        part_one(&parse_cups(input))
+    }
+
+    fn part2(&self, input: &str) -> Option<String> {
+        Some(part_two(&parse_cups(input)).to_string())
+    }
+}
+
+puzzle!(2020, 23, Day23, "inputs/023.txt");
 
-    let start = Instant::now();
-    println!("Part two: {} in {:#?}", part_two(&input), start.elapsed());
+fn parse_cups(input: &str) -> Vec<u8> {
+    input
+        .bytes()
+        .filter(|&b| b != b'\n')
+        .map(|b| b - b'0')
+        .collect()
 }
 
 fn part_one(input: &[u8]) -> String {
@@ -107,6 +115,6 @@ fn part_two(input: &[u8]) -> usize {
     }
 
     let a = cups[1_usize] as usize;
-    let b = cups[a as usize] as usize;
-    (a * b) as usize
+    let b = cups[a] as usize;
+    a * b
 }
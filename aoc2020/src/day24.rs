@@ -1,10 +1,25 @@
-use std::{
-    collections::{HashMap, HashSet},
-    time::Instant,
-};
+use std::collections::{HashMap, HashSet};
 
-fn main() {
-    let tiles: Vec<(isize, isize)> = include_bytes!("../../inputs/024.txt")
+use aoc_solution::{puzzle, Solution};
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    fn part1(&self, input: &str) -> String {
+        part_one(&parse_tiles(input)).to_string()
+    }
+
+    fn part2(&self, input: &str) -> Option<String> {
+        Some(part_two(&parse_tiles(input)).to_string())
+    }
+}
+
+puzzle!(2020, 24, Day24, "inputs/024.txt");
+
+/// Each line walked from the reference tile, every line must end in a newline
+fn parse_tiles(input: &str) -> Vec<(isize, isize)> {
+    input
+        .as_bytes()
         .split_inclusive(|&b| b == b'\n')
         .map(|line| {
             line.windows(2)
@@ -18,13 +33,7 @@ fn main() {
                     _ => panic!(),
                 })
         })
-        .collect();
-
-    let start = Instant::now();
-    println!("Part one: {} in {:#?}", part_one(&tiles), start.elapsed());
-
-    let start = Instant::now();
-    println!("Part two: {} in {:#?}", part_two(&tiles), start.elapsed());
+        .collect()
 }
 
 fn part_one(tiles: &[(isize, isize)]) -> usize {
@@ -1,20 +1,19 @@
-use std::time::Instant;
-
-fn main() {
-    let (card_public, door_public) = include_str!("../../inputs/025.txt")
-        .split_once("\n")
-        .unwrap();
-    let card_public = card_public.parse::<usize>().unwrap();
-    let door_public = door_public.parse::<usize>().unwrap();
-
-    let start = Instant::now();
-    println!(
-        "Part one: {} in {:#?}",
-        part_one(card_public, door_public),
-        start.elapsed()
-    );
+use aoc_solution::{puzzle, Solution};
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    fn part1(&self, input: &str) -> String {
+        let (card_public, door_public) = input.trim_end().split_once("\n").unwrap();
+        let card_public = card_public.parse::<usize>().unwrap();
+        let door_public = door_public.parse::<usize>().unwrap();
+
+        part_one(card_public, door_public).to_string()
+    }
 }
 
+puzzle!(2020, 25, Day25, "inputs/025.txt");
+
 fn part_one(card_public: usize, door_public: usize) -> usize {
     let (mut card_public_new, mut door_public_new, mut card_encryption, mut door_encryption) =
         (1, 1, 1, 1);
@@ -1,27 +1,19 @@
-use std::time::Instant;
+use aoc_solution::{puzzle, Solution};
 
-fn main() {
-    let input = include_str!("../../inputs/003.txt");
+pub struct Day3;
 
-    let map = parse_map(input);
-
-    // Part 1
-    let start = Instant::now();
-    let count = count_trees_encountered(&map);
-    let duration = start.elapsed();
-
-    println!("Trees encountered: {}", count);
-    println!("Time taken: {:?}", duration);
-
-    // Part 2
-    let start = Instant::now();
-    let product = product_all_route_methods(&map);
-    let duration = start.elapsed();
+impl Solution for Day3 {
+    fn part1(&self, input: &str) -> String {
+        count_trees_encountered(&parse_map(input)).to_string()
+    }
 
-    println!("Trees encountered all methods: {}", product);
-    println!("Time taken: {:?}", duration);
+    fn part2(&self, input: &str) -> Option<String> {
+        Some(product_all_route_methods(&parse_map(input)).to_string())
+    }
 }
 
+puzzle!(2020, 3, Day3, "inputs/003.txt");
+
 fn parse_map(input: &str) -> Vec<Vec<Terrain>> {
     let lines: Vec<&str> = input.lines().collect();
 
@@ -1,76 +1,83 @@
-use std::{collections::HashMap, time::Instant};
+use std::collections::HashMap;
 
+use aoc_solution::{puzzle, Solution};
 use regex::Regex;
 
-fn main() {
-    let start = Instant::now();
-    part_one();
-    let mut duration = start.elapsed();
-    println!("Time taken: {:#?}", duration);
-    part_two();
-    duration = start.elapsed() - duration;
-    println!("Time taken: {:#?}", duration)
+pub struct Day4;
+
+impl Solution for Day4 {
+    fn part1(&self, input: &str) -> String {
+        part_one(input).to_string()
+    }
+
+    fn part2(&self, input: &str) -> Option<String> {
+        Some(part_two(input).to_string())
+    }
 }
 
-fn part_one() {
+puzzle!(2020, 4, Day4, "inputs/004.txt");
+
+fn part_one(input: &str) -> usize {
     let required_fields: Vec<&str> = vec!["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
 
-    println!(
-        "Part one: {}",
-        // includes a utf8 encoded file as a &str
-        include_str!("../../inputs/004.txt")
-            // create an iterator where each element is split based on blank line
-            .split("\n\n")
-            // turn each passport entry into a hash map
-            .map(|entry| entry
+    input
+        // create an iterator where each element is split based on blank line
+        .split("\n\n")
+        // turn each passport entry into a hash map
+        .map(|entry| {
+            entry
                 // take each element of iterator (passport entry) and split it through whitespace (handles multiple spaces etc.)
                 .split_ascii_whitespace()
                 // splits a string on the first appearance of the characeter, returns an iterator with elements of before and after arg
                 // creates the field (before the :) and the value (after the :)
                 .map(|field| field.split_once(':').unwrap())
                 // collect the two values into a hash map
-                .collect::<HashMap<&str, &str>>())
-            // remove passports that don't contain all fields
-            .filter(|passport| required_fields
+                .collect::<HashMap<&str, &str>>()
+        })
+        // remove passports that don't contain all fields
+        .filter(|passport| {
+            required_fields
                 .iter()
                 // returns only those which the closure holds true for every single element
-                .all(|field| passport.contains_key(field)))
-            // count how many valid passports there are
-            .count()
-    );
+                .all(|field| passport.contains_key(field))
+        })
+        // count how many valid passports there are
+        .count()
 }
 
-fn part_two() {
+fn part_two(input: &str) -> usize {
     let required_fields: Vec<&str> = vec!["iyr", "byr", "eyr", "hgt", "hcl", "ecl", "pid"];
 
-    println!(
-        "Part two: {}",
-        // includes a utf8 encoded file as a &str
-        include_str!("../../inputs/004.txt")
-            // create an iterator where each element is split based on blank line
-            .split("\n\n")
-            // turn each passport entry into a hash map
-            .map(|entry| entry
+    input
+        // create an iterator where each element is split based on blank line
+        .split("\n\n")
+        // turn each passport entry into a hash map
+        .map(|entry| {
+            entry
                 // take each element of iterator (passport entry) and split it through whitespace (handles multiple spaces etc.)
                 .split_ascii_whitespace()
                 // splits a string on the first appearance of the characeter, returns an iterator with elements of before and after arg
                 // creates the field (before the :) and the value (after the :)
                 .map(|field| field.split_once(':').unwrap())
                 // collect the two values into a hash map
-                .collect::<HashMap<&str, &str>>())
-            // remove passports that don't contain all fields
-            .filter(|passport| required_fields
+                .collect::<HashMap<&str, &str>>()
+        })
+        // remove passports that don't contain all fields
+        .filter(|passport| {
+            required_fields
                 .iter()
                 // returns only those which the closure holds true for every single element
-                .all(|field| passport.contains_key(field)))
-            // remove passports that don't pass all the validation functions
-            .filter(|passport| passport
+                .all(|field| passport.contains_key(field))
+        })
+        // remove passports that don't pass all the validation functions
+        .filter(|passport| {
+            passport
                 .iter()
                 // the predicate this time is a method which validates based on what type of field it is given
-                .all(|(field, value)| validate(field, value)))
-            // count how many valid passports there are
-            .count()
-    );
+                .all(|(field, value)| validate(field, value))
+        })
+        // count how many valid passports there are
+        .count()
 }
 
 /// Validates the value against the validation based on the field
@@ -1,22 +1,26 @@
-use std::time::Instant;
+use aoc_solution::{puzzle, Solution};
 
-fn main() {
-    let start = Instant::now();
-    println!("Part one: {}", part_one());
-    let mut duration = start.elapsed();
-    println!("Time taken: {:#?}", duration);
-    println!("Part two: {}", part_two());
-    duration = start.elapsed() - duration;
-    println!("Time taken: {:#?}", duration);
+pub struct Day5;
+
+impl Solution for Day5 {
+    fn part1(&self, input: &str) -> String {
+        part_one(input).to_string()
+    }
+
+    fn part2(&self, input: &str) -> Option<String> {
+        Some(part_two(input).to_string())
+    }
 }
 
+puzzle!(2020, 5, Day5, "inputs/005.txt");
+
 // Important to realise that this problem can essentially boil down to setting binary digits based on the letter
 // F and L are 0 bit
 // R and B are 1 bit
 // The last part of the id system is to do row * 8 + column
 // This is the same as bit shifting right by 3 (creating space for 3 more bits) and setting the first 3 bits as the column
-fn part_one() -> usize {
-    include_str!("../../inputs/005.txt")
+fn part_one(input: &str) -> usize {
+    input
         .split('\n')
         .map(|seat| {
             seat.chars()
@@ -38,8 +42,8 @@ fn part_one() -> usize {
         .unwrap()
 }
 
-fn part_two() -> usize {
-    let mut ids = include_str!("../../inputs/005.txt")
+fn part_two(input: &str) -> usize {
+    let mut ids = input
         .split('\n')
         .map(|seat| {
             seat.chars().fold(0, |id, char| {
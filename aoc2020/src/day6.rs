@@ -1,22 +1,21 @@
-use std::time::Instant;
+use aoc_solution::{puzzle, Solution};
 
-fn main() {
-    let input = include_str!("../../inputs/006.txt");
+pub struct Day6;
 
-    let start = Instant::now();
-    println!("Part one: {}", part_one_old(input));
-    println!("Time taken: {:#?}", start.elapsed());
+impl Solution for Day6 {
+    fn part1(&self, input: &str) -> String {
+        part_one_bits(input).to_string()
+    }
 
-    let start = Instant::now();
-    println!("Part one bits: {}", part_one_bits(input));
-    println!("Time taken: {:#?}", start.elapsed());
-
-    let start = Instant::now();
-    println!("Part two: {}", part_two(input));
-    println!("Time taken: {:#?}", start.elapsed());
+    fn part2(&self, input: &str) -> Option<String> {
+        Some(part_two(input).to_string())
+    }
 }
 
-fn part_one_old(input: &str) -> usize {
+puzzle!(2020, 6, Day6, "inputs/006.txt");
+
+/// The first go at part one, before counting answers with bits
+pub fn part_one_old(input: &str) -> usize {
     input
         // split iterator over every blank line
         .split("\n\n")
@@ -1,23 +1,23 @@
-use std::time::Instant;
-
+use aoc_solution::{puzzle, Solution};
 use itertools::Itertools;
 use multimap::MultiMap;
 
-fn main() {
-    let input = include_str!("../../inputs/007.txt");
-    let rules = parse_rules(input);
+pub struct Day7;
 
-    let mut start = Instant::now();
-    println!("Part one answer: {}", part_one(&rules));
-    println!("Time taken: {:#?}", start.elapsed());
+impl Solution for Day7 {
+    fn part1(&self, input: &str) -> String {
+        part_one(&parse_rules(input)).to_string()
+    }
 
-    start = Instant::now();
-    println!("Part two answer: {}", part_two(&rules));
-    println!("Time taken: {:#?}", start.elapsed());
+    fn part2(&self, input: &str) -> Option<String> {
+        Some(part_two(&parse_rules(input)).to_string())
+    }
 }
 
+puzzle!(2020, 7, Day7, "inputs/007.txt");
+
 fn part_one(rules: &Rules) -> usize {
-    let reverse_rules = reverse_graph(&rules);
+    let reverse_rules = reverse_graph(rules);
 
     let goal = ("shiny", "gold");
     walk(&reverse_rules, &goal).unique().count()
@@ -25,7 +25,7 @@ fn part_one(rules: &Rules) -> usize {
 
 fn part_two(rules: &Rules) -> usize {
     let goal = ("shiny", "gold");
-    walk_and_count(&rules, &goal).sum()
+    walk_and_count(rules, &goal).sum()
 }
 
 type BagDescription<'a> = (&'a str, &'a str);
@@ -77,12 +77,11 @@ fn parse_rules(input: &str) -> Rules<'_> {
 fn reverse_graph<'a>(graph: &Rules<'a>) -> Rules<'a> {
     graph
         .iter_all()
-        .map(|(&node, neighbours)| {
+        .flat_map(|(&node, neighbours)| {
             neighbours
                 .iter()
                 .map(move |&(quantity, neighbour)| (neighbour, (quantity, node)))
         })
-        .flatten()
         .collect()
 }
 
@@ -95,8 +94,9 @@ fn walk<'iter, 'elems: 'iter>(
             .get_vec(root)
             .into_iter()
             .flatten()
-            .map(move |&(_, neighbour)| std::iter::once(neighbour).chain(walk(graph, &neighbour)))
-            .flatten(),
+            .flat_map(move |&(_, neighbour)| {
+                std::iter::once(neighbour).chain(walk(graph, &neighbour))
+            }),
     )
 }
 
@@ -109,10 +109,9 @@ fn walk_and_count<'iter, 'elems: 'iter>(
             .get_vec(root)
             .into_iter()
             .flatten()
-            .map(move |&(quantity, contents)| {
+            .flat_map(move |&(quantity, contents)| {
                 std::iter::once(quantity)
                     .chain(walk_and_count(graph, &contents).map(move |x| x * quantity))
-            })
-            .flatten(),
+            }),
     )
 }
@@ -1,7 +1,23 @@
-use std::time::Instant;
+use aoc_solution::{puzzle, Solution};
 
-fn main() {
-    let mut instructions: Vec<(&[u8], bool, usize)> = include_bytes!("../../inputs/008.txt")
+pub struct Day8;
+
+impl Solution for Day8 {
+    fn part1(&self, input: &str) -> String {
+        part_one(&parse_instructions(input)).to_string()
+    }
+
+    fn part2(&self, input: &str) -> Option<String> {
+        Some(part_two(parse_instructions(input)).to_string())
+    }
+}
+
+puzzle!(2020, 8, Day8, "inputs/008.txt");
+
+fn parse_instructions(input: &str) -> Vec<(&[u8], bool, usize)> {
+    input
+        .trim_end()
+        .as_bytes()
         .split(|b| b == &b'\n')
         .map(|line| {
             (
@@ -13,9 +29,11 @@ fn main() {
                     .expect("not number"),
             )
         })
-        .collect();
+        .collect()
+}
 
-    let (mut visited, mut pc, mut acc, mut start) = (vec![], 0, 0, Instant::now());
+fn part_one(instructions: &[(&[u8], bool, usize)]) -> isize {
+    let (mut visited, mut pc, mut acc) = (vec![], 0, 0);
 
     while !visited.contains(&pc) {
         visited.push(pc);
@@ -41,13 +59,14 @@ fn main() {
         }
     }
 
-    println!("Part one: {}", acc);
-    println!("Time taken: {:#?}", start.elapsed());
+    acc
+}
 
-    start = Instant::now();
+fn part_two(mut instructions: Vec<(&[u8], bool, usize)>) -> isize {
+    let (mut pc, mut acc) = (0, 0);
     let mut next_to_try = 0;
 
-    while pc < 591 {
+    while pc < instructions.len() {
         match instructions[next_to_try] {
             (b"jmp", _, _) => {
                 instructions[next_to_try].0 = b"nop";
@@ -68,14 +87,13 @@ fn main() {
         next_to_try += 1;
     }
 
-    println!("Part two: {}", acc);
-    println!("Time taken: {:#?}", start.elapsed());
+    acc
 }
 
 fn run(program: &[(&[u8], bool, usize)]) -> (usize, isize) {
     let (mut visited, mut pc, mut acc) = (vec![], 0, 0);
 
-    while !visited.contains(&pc) && pc < 591 {
+    while !visited.contains(&pc) && pc < program.len() {
         visited.push(pc);
         match program[pc] {
             (b"acc", is_neg, num) => {
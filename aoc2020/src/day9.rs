@@ -1,19 +1,22 @@
-use std::time::Instant;
-
-fn main() {
-    let numbers: Vec<usize> = include_str!("../../inputs/009.txt")
-        .lines()
-        .map(|line| line.parse().unwrap())
-        .collect();
-
-    let start = Instant::now();
-    let answer = part_one(&numbers);
-    println!("Part one: {}", answer);
-    println!("Time taken: {:#?}", start.elapsed());
-
-    let start = Instant::now();
-    println!("Part two: {}", part_two(&numbers, answer));
-    println!("Time taken: {:#?}", start.elapsed());
+use aoc_solution::{puzzle, Solution};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    fn part1(&self, input: &str) -> String {
+        part_one(&parse_numbers(input)).to_string()
+    }
+
+    fn part2(&self, input: &str) -> Option<String> {
+        let numbers = parse_numbers(input);
+        Some(part_two(&numbers, part_one(&numbers)).to_string())
+    }
+}
+
+puzzle!(2020, 9, Day9, "inputs/009.txt");
+
+fn parse_numbers(input: &str) -> Vec<usize> {
+    input.lines().map(|line| line.parse().unwrap()).collect()
 }
 
 fn part_one(numbers: &[usize]) -> usize {
@@ -0,0 +1,54 @@
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day2;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+/// Every day, inputs go in inputs/ numbered by day like 001.txt
+pub const PUZZLES: &[aoc_solution::Puzzle] = &[
+    day1::PUZZLE,
+    day2::PUZZLE,
+    day3::PUZZLE,
+    day4::PUZZLE,
+    day5::PUZZLE,
+    day6::PUZZLE,
+    day7::PUZZLE,
+    day8::PUZZLE,
+    day9::PUZZLE,
+    day10::PUZZLE,
+    day11::PUZZLE,
+    day12::PUZZLE,
+    day13::PUZZLE,
+    day14::PUZZLE,
+    day15::PUZZLE,
+    day16::PUZZLE,
+    day17::PUZZLE,
+    day18::PUZZLE,
+    day19::PUZZLE,
+    day20::PUZZLE,
+    day21::PUZZLE,
+    day22::PUZZLE,
+    day23::PUZZLE,
+    day24::PUZZLE,
+    day25::PUZZLE,
+];
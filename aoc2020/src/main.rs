@@ -1,3 +1,3 @@
-fn main() {
-    println!("Hello, world!");
+fn main() -> std::process::ExitCode {
+    aoc_solution::main_for_day(aoc2020::PUZZLES)
 }
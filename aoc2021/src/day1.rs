@@ -1,3 +1,5 @@
+use aoc_solution::{puzzle, Solution};
+
 #[aoc_generator(day1)]
 pub fn parse_input(input: &str) -> Vec<u32> {
     input.lines().map(|x| x.parse::<u32>().unwrap()).collect()
@@ -25,6 +27,20 @@ pub fn solve_part2(input: &[u32]) -> u32 {
     count
 }
 
+pub struct Day1;
+
+impl Solution for Day1 {
+    fn part1(&self, input: &str) -> String {
+        solve_part1(&parse_input(input)).to_string()
+    }
+
+    fn part2(&self, input: &str) -> Option<String> {
+        Some(solve_part2(&parse_input(input)).to_string())
+    }
+}
+
+puzzle!(2021, 1, Day1, "input/2021/day1.txt");
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,3 +1,5 @@
+use aoc_solution::{puzzle, Solution};
+
 #[derive(Debug)]
 pub struct Command {
     direction: Direction,
@@ -17,7 +19,7 @@ pub fn parse_input(input: &str) -> Vec<Command> {
         .lines()
         .map(|line| line.split_once(" ").unwrap())
         .map(|cmd| {
-            return match cmd {
+            match cmd {
                 ("forward", _) => Command {
                     direction: Direction::Forward,
                     amount: cmd.1.parse().unwrap(),
@@ -31,7 +33,7 @@ pub fn parse_input(input: &str) -> Vec<Command> {
                     amount: cmd.1.parse().unwrap(),
                 },
                 (_, _) => panic!("Bad line"),
-            };
+            }
         })
         .collect()
 }
@@ -62,6 +64,20 @@ pub fn solve_part2(input: &[Command]) -> usize {
     position.0 * position.1
 }
 
+pub struct Day2;
+
+impl Solution for Day2 {
+    fn part1(&self, input: &str) -> String {
+        solve_part1(&parse_input(input)).to_string()
+    }
+
+    fn part2(&self, input: &str) -> Option<String> {
+        Some(solve_part2(&parse_input(input)).to_string())
+    }
+}
+
+puzzle!(2021, 2, Day2, "input/2021/day2.txt");
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -70,13 +86,13 @@ mod test {
 
     #[test]
     fn test_part1() {
-        let commands = parse_input(&INPUT);
+        let commands = parse_input(INPUT);
         assert_eq!(solve_part1(&commands), 150);
     }
 
     #[test]
     fn test_part2() {
-        let commands = parse_input(&INPUT);
+        let commands = parse_input(INPUT);
         assert_eq!(solve_part2(&commands), 900);
     }
 }
@@ -1,3 +1,5 @@
+use aoc_solution::{puzzle, Solution};
+
 #[aoc_generator(day3)]
 pub fn parse_input(input: &str) -> (usize, Vec<usize>) {
     let num_bits = input.lines().next().unwrap().len();
@@ -33,10 +35,22 @@ fn solve_part1(input: &(usize, Vec<usize>)) -> usize {
 }
 
 #[aoc(day3, part2)]
-fn solve_part2(input: &(usize, Vec<usize>)) -> usize {
+fn solve_part2(_input: &(usize, Vec<usize>)) -> usize {
     0
 }
 
+pub struct Day3;
+
+impl Solution for Day3 {
+    fn part1(&self, input: &str) -> String {
+        solve_part1(&parse_input(input)).to_string()
+    }
+
+    // Part 2 isn't solved yet
+}
+
+puzzle!(2021, 3, Day3, "input/2021/day3.txt");
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -6,3 +6,6 @@ mod day2;
 mod day3;
 
 aoc_lib! { year = 2021 }
+
+/// Every day solved so far, inputs are where cargo aoc puts them
+pub const PUZZLES: &[aoc_solution::Puzzle] = &[day1::PUZZLE, day2::PUZZLE, day3::PUZZLE];
@@ -1,7 +1,3 @@
-extern crate aoc2021;
-extern crate aoc_runner;
-extern crate aoc_runner_derive;
-
-use aoc_runner_derive::aoc_main;
-
-aoc_main! { lib = aoc2021 }
+fn main() -> std::process::ExitCode {
+    aoc_solution::main_for_day(aoc2021::PUZZLES)
+}
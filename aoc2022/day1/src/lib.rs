@@ -0,0 +1,34 @@
+use aoc_solution::{puzzle, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    fn part1(&self, input: &str) -> String {
+        part_1(input).to_string()
+    }
+
+    fn part2(&self, input: &str) -> Option<String> {
+        Some(part_2(input).to_string())
+    }
+}
+
+puzzle!(2022, 1, Day1);
+
+fn part_1(input: &str) -> usize {
+    input
+        .split("\n\n")
+        .map(|chunk| (chunk.lines().map(|line| line.parse::<usize>().unwrap())).sum())
+        .max()
+        .unwrap()
+}
+
+fn part_2(input: &str) -> usize {
+    let mut elf_sums = input
+        .split("\n\n")
+        .map(|chunk| (chunk.lines().map(|line| line.parse::<usize>().unwrap())).sum::<usize>())
+        .collect::<Vec<usize>>();
+
+    elf_sums.sort_unstable();
+
+    elf_sums.into_iter().rev().take(3).sum()
+}
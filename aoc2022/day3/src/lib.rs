@@ -0,0 +1,42 @@
+use aoc_solution::{puzzle, Solution};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    fn part1(&self, input: &str) -> String {
+        part_1(input).to_string()
+    }
+}
+
+puzzle!(2022, 3, Day3);
+
+fn part_1(input: &str) -> u32 {
+    input
+        .lines()
+        .map(|rucksack| rucksack.chars().collect::<Vec<char>>())
+        .map(|x| x.to_owned())
+        .map(|items| {
+            let halves = items.split_at(items.len() / 2);
+            (halves.0.to_owned(), halves.1.to_owned())
+        })
+        .map(|(first, second)| find_intersect_item_as_value(&first, &second))
+        .map(get_item_value)
+        .sum::<u32>()
+}
+
+fn find_intersect_item_as_value(first: &[char], second: &[char]) -> char {
+    first
+        .iter()
+        .find(|item_in_first| second.contains(item_in_first))
+        .expect("couldn't find interserct item")
+        .to_owned()
+}
+
+fn get_item_value(item: char) -> u32 {
+    let as_u32 = item as u32;
+    if as_u32 > 97 {
+        return as_u32 - 96;
+    }
+
+    as_u32 - 38
+}
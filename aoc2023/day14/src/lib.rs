@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+
+use aoc_solution::{puzzle, Solution};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    fn part1(&self, input: &str) -> String {
+        part_1(input).to_string()
+    }
+
+    fn part2(&self, input: &str) -> Option<String> {
+        Some(part_2(input).to_string())
+    }
+}
+
+puzzle!(2023, 14, Day14);
+
+fn part_1(input: &str) -> usize {
+    let mut grid: Vec<Vec<_>> = input.lines().map(|line| line.chars().collect()).collect();
+
+    tilt_north(&mut grid);
+
+    count_load(&grid)
+}
+
+fn part_2(input: &str) -> usize {
+    let mut grid: Vec<Vec<_>> = input.lines().map(|line| line.chars().collect()).collect();
+
+    let mut seen: HashMap<Vec<Vec<char>>, usize> = HashMap::new();
+
+    let num_cycles = 1000000000;
+
+    for cycle in 1..num_cycles {
+        for _ in 0..4 {
+            tilt_north(&mut grid);
+            grid = rotate_left(&grid);
+        }
+
+        if let Some(previous_seen) = seen.get(&grid) {
+            let remaining = num_cycles - cycle;
+            // how often does this layout come around
+            let period = cycle - previous_seen;
+
+            // if its a divisor then we know by the end we are gonna be the same as we are now
+            if remaining % period == 0 {
+                break;
+            }
+        }
+
+        seen.insert(grid.clone(), cycle);
+    }
+
+    count_load(&grid)
+}
+
+fn tilt_north(grid: &mut [Vec<char>]) {
+    for row in 1..grid.len() {
+        for col in 0..grid[0].len() {
+            let mut pointer = row;
+
+            while pointer > 0 {
+                if grid[pointer - 1][col] == '.' && grid[pointer][col] == 'O' {
+                    grid[pointer][col] = '.';
+                    grid[pointer - 1][col] = 'O';
+
+                    pointer -= 1;
+                } else {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+fn count_load(grid: &[Vec<char>]) -> usize {
+    (0..grid.len())
+        .map(|row| {
+            (0..grid[0].len())
+                .filter(|&col| grid[row][col] == 'O')
+                .map(|_col| grid.len() - row)
+                .sum::<usize>()
+        })
+        .sum()
+}
+
+fn rotate_left(grid: &[Vec<char>]) -> Vec<Vec<char>> {
+    let num_rows = grid.len();
+    let num_cols = grid[0].len();
+
+    let mut rotated_grid = vec![vec!['X'; num_cols]; num_rows];
+
+    for (row, line) in grid.iter().enumerate() {
+        for (col, &cell) in line.iter().enumerate() {
+            let new_row = col;
+            let new_col = num_rows - row - 1;
+
+            rotated_grid[new_row][new_col] = cell;
+        }
+    }
+
+    rotated_grid
+}
+
+#[test]
+fn test_tilt_north() {
+    let original = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+    let mut original: Vec<Vec<_>> = original
+        .lines()
+        .map(|line| line.chars().collect())
+        .collect();
+
+    let after = "OOOO.#.O..
+OO..#....#
+OO..O##..O
+O..#.OO...
+........#.
+..#....#.#
+..O..#.O.O
+..O.......
+#....###..
+#....#....";
+
+    let after: Vec<Vec<_>> = after.lines().map(|line| line.chars().collect()).collect();
+
+    tilt_north(&mut original);
+
+    assert_eq!(original, after);
+}
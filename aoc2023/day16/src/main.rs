@@ -1,204 +1,3 @@
-use std::collections::{HashMap, HashSet, VecDeque};
-
-#[derive(PartialEq, Eq, Hash, Clone, Copy)]
-enum Direction {
-    North,
-    East,
-    South,
-    West,
-}
-
-#[derive(PartialEq, Eq, Hash, Clone, Copy)]
-struct Coord {
-    x: isize,
-    y: isize,
-}
-
-fn main() {
-    let input = include_str!("input.txt");
-
-    let answer1 = part_1(input);
-    println!("Part 1: {answer1}");
-
-    let answer2 = part_2(input);
-    println!("Part 2: {answer2}");
-}
-
-fn part_1(input: &str) -> usize {
-    let grid: Vec<Vec<_>> = input.lines().map(|line| line.as_bytes().into()).collect();
-
-    bounce_light(&grid, (Coord { x: 0, y: 0 }, Direction::East))
-}
-
-fn part_2(input: &str) -> usize {
-    let grid: Vec<Vec<_>> = input.lines().map(|line| line.as_bytes().into()).collect();
-
-    let mut max_energised = 0;
-
-    for y in 0..grid.len() {
-        max_energised = max_energised.max(bounce_light(
-            &grid,
-            (
-                Coord {
-                    x: 0,
-                    y: y as isize,
-                },
-                Direction::East,
-            ),
-        ));
-        max_energised = max_energised.max(bounce_light(
-            &grid,
-            (
-                Coord {
-                    x: (grid[0].len() - 1) as isize,
-                    y: y as isize,
-                },
-                Direction::West,
-            ),
-        ));
-    }
-
-    for x in 0..grid[0].len() {
-        max_energised = max_energised.max(bounce_light(
-            &grid,
-            (
-                Coord {
-                    x: x as isize,
-                    y: 0,
-                },
-                Direction::South,
-            ),
-        ));
-        max_energised = max_energised.max(bounce_light(
-            &grid,
-            (
-                Coord {
-                    x: x as isize,
-                    y: (grid.len() - 1) as isize,
-                },
-                Direction::North,
-            ),
-        ));
-    }
-
-    max_energised
-}
-
-fn bounce_light(grid: &Vec<Vec<u8>>, start: (Coord, Direction)) -> usize {
-    let mut stack: VecDeque<(Coord, Direction)> = VecDeque::with_capacity(20_000);
-    let mut seen: HashSet<(Coord, Direction)> = HashSet::new();
-
-    let rows = grid.len();
-    let cols = grid[0].len();
-
-    let mut energised: Vec<Vec<bool>> = vec![vec![false; cols]; rows];
-
-    stack.push_back(start);
-
-    while let Some((position, direction)) = stack.pop_front() {
-        if (position.y < 0
-            || position.y >= rows as isize
-            || position.x < 0
-            || position.x >= cols as isize)
-            || !seen.insert((position, direction))
-        {
-            continue;
-        }
-
-        let x = position.x as usize;
-        let y = position.y as usize;
-
-        energised[y][x] = true;
-
-        match grid[y][x] {
-            b'.' => stack.push_back((point_in_direction(x, y, direction), direction)),
-            b'/' => {
-                match direction {
-                    Direction::North => stack
-                        .push_back((point_in_direction(x, y, Direction::East), Direction::East)),
-                    Direction::East => stack
-                        .push_back((point_in_direction(x, y, Direction::North), Direction::North)),
-                    Direction::South => stack
-                        .push_back((point_in_direction(x, y, Direction::West), Direction::West)),
-                    Direction::West => stack
-                        .push_back((point_in_direction(x, y, Direction::South), Direction::South)),
-                }
-            }
-            b'\\' => {
-                match direction {
-                    Direction::North => stack
-                        .push_back((point_in_direction(x, y, Direction::West), Direction::West)),
-                    Direction::East => stack
-                        .push_back((point_in_direction(x, y, Direction::South), Direction::South)),
-                    Direction::South => stack
-                        .push_back((point_in_direction(x, y, Direction::East), Direction::East)),
-                    Direction::West => stack
-                        .push_back((point_in_direction(x, y, Direction::North), Direction::North)),
-                }
-            }
-            b'-' => match direction {
-                Direction::East | Direction::West => {
-                    stack.push_back((point_in_direction(x, y, direction), direction))
-                }
-                Direction::North | Direction::South => {
-                    stack.push_back((point_in_direction(x, y, Direction::East), Direction::East));
-                    stack.push_back((point_in_direction(x, y, Direction::West), Direction::West));
-                }
-            },
-            b'|' => match direction {
-                Direction::North | Direction::South => {
-                    stack.push_back((point_in_direction(x, y, direction), direction))
-                }
-                Direction::East | Direction::West => {
-                    stack.push_back((point_in_direction(x, y, Direction::North), Direction::North));
-                    stack.push_back((point_in_direction(x, y, Direction::South), Direction::South));
-                }
-            },
-            _ => panic!("Unkown grid symbol"),
-        }
-    }
-
-    energised
-        .iter()
-        .map(|row| row.iter().filter(|&energised| *energised).count())
-        .sum()
-}
-
-fn point_in_direction(x: usize, y: usize, direction: Direction) -> Coord {
-    match direction {
-        Direction::North => Coord {
-            x: x as isize,
-            y: y as isize - 1,
-        },
-        Direction::East => Coord {
-            x: x as isize + 1,
-            y: y as isize,
-        },
-        Direction::South => Coord {
-            x: x as isize,
-            y: y as isize + 1,
-        },
-        Direction::West => Coord {
-            x: x as isize - 1,
-            y: y as isize,
-        },
-    }
-}
-
-#[test]
-fn part_1_test() {
-    let input = r#".|...\....
-|.-.\.....
-.....|-...
-........|.
-..........
-.........\
-..../.\\..
-.-.-/..|..
-.|....-|.\
-..//.|...."#;
-
-    let energised = part_1(input);
-
-    assert_eq!(energised, 46);
+fn main() -> std::process::ExitCode {
+    aoc_solution::main(&aoc2023_day16::PUZZLE)
 }
@@ -0,0 +1,238 @@
+use std::collections::{hash_map::Entry, HashMap, VecDeque};
+
+use aoc_solution::{puzzle, Solution};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    fn part1(&self, input: &str) -> String {
+        part_1(input).to_string()
+    }
+
+    fn part2(&self, input: &str) -> Option<String> {
+        Some(part_2(input).to_string())
+    }
+}
+
+puzzle!(2023, 20, Day20);
+
+#[derive(Debug)]
+enum Module<'a> {
+    Broadcast(Vec<&'a str>),
+    FlipFlop(bool, Vec<&'a str>),
+    Conjuction(HashMap<&'a str, bool>, Vec<&'a str>),
+}
+
+fn part_1(input: &str) -> usize {
+    let mut modules = parse_modules(input);
+
+    let mut low_signals = 0;
+    let mut high_signals = 0;
+
+    for _ in 0..1000 {
+        let mut queue = VecDeque::from_iter([("button", "broadcaster", false)]);
+
+        while let Some((prev, current, signal)) = queue.pop_front() {
+            if signal {
+                high_signals += 1
+            } else {
+                low_signals += 1
+            }
+
+            if let Entry::Occupied(mut current_module) = modules.entry(current) {
+                let current_module = current_module.get_mut();
+
+                match current_module {
+                    Module::Broadcast(destinations) => {
+                        for destination in destinations {
+                            queue.push_back((current, destination, signal));
+                        }
+                    }
+                    Module::FlipFlop(ref mut ff_state, destinations) => {
+                        match (signal, &ff_state) {
+                            // turns off and sends low pulse
+                            (false, true) => {
+                                *ff_state = false;
+                                for destination in destinations {
+                                    queue.push_back((current, destination, false));
+                                }
+                            }
+                            // turns on and sends high pulse
+                            (false, false) => {
+                                *ff_state = true;
+                                for destination in destinations {
+                                    queue.push_back((current, destination, true));
+                                }
+                            }
+                            // high pulse does nothing
+                            _ => {}
+                        }
+                    }
+                    Module::Conjuction(ref mut inputs, destinations) => {
+                        // update its memory for the received signal
+                        inputs.insert(prev, signal);
+
+                        let signal_to_send = !inputs.values().all(|&sig| sig);
+
+                        for destination in destinations {
+                            queue.push_back((current, destination, signal_to_send));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    low_signals * high_signals
+}
+
+fn part_2(input: &str) -> usize {
+    let mut modules = parse_modules(input);
+
+    // in my input the conjugate ll is the only pointer to rx
+    // there are 4 connectors to ll, they are: [kv, vb, kl, vm]
+    // so work out the period of all of its sources becoming high and then find the lcm of those
+    // to work out when they are all high such that ll is low and therefore rx is low
+    let num_ll_sources = 4;
+    let mut ll_source_periods: HashMap<&str, usize> = HashMap::new();
+
+    let mut cycles = 0;
+
+    'search: loop {
+        cycles += 1;
+
+        let mut queue = VecDeque::from_iter([("button", "broadcaster", false)]);
+
+        while let Some((prev, current, signal)) = queue.pop_front() {
+            // check for ll then check the prev to get one of its inputs
+            if current == "ll" && signal {
+                ll_source_periods.entry(prev).or_insert(cycles);
+
+                if ll_source_periods.len() == num_ll_sources {
+                    break 'search;
+                }
+            }
+
+            if let Entry::Occupied(mut current_module) = modules.entry(current) {
+                let current_module = current_module.get_mut();
+
+                match current_module {
+                    Module::Broadcast(destinations) => {
+                        for destination in destinations {
+                            queue.push_back((current, destination, signal));
+                        }
+                    }
+                    Module::FlipFlop(ref mut ff_state, destinations) => {
+                        match (signal, &ff_state) {
+                            // turns off and sends low pulse
+                            (false, true) => {
+                                *ff_state = false;
+                                for destination in destinations {
+                                    queue.push_back((current, destination, false));
+                                }
+                            }
+                            // turns on and sends high pulse
+                            (false, false) => {
+                                *ff_state = true;
+                                for destination in destinations {
+                                    queue.push_back((current, destination, true));
+                                }
+                            }
+                            // high pulse does nothing
+                            _ => {}
+                        }
+                    }
+                    Module::Conjuction(ref mut inputs, destinations) => {
+                        // update its memory for the received signal
+                        inputs.insert(prev, signal);
+
+                        let signal_to_send = !inputs.values().all(|&sig| sig);
+
+                        for destination in destinations {
+                            queue.push_back((current, destination, signal_to_send));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    println!("Periods: {:?}", ll_source_periods);
+
+    ll_source_periods
+        .values()
+        .fold(1, |curr, &running| num::integer::lcm(curr, running))
+}
+
+fn parse_modules(input: &str) -> HashMap<&str, Module<'_>> {
+    let mut modules: HashMap<_, _> = input
+        .lines()
+        .map(|line| {
+            let (module, rest) = line.split_once(" -> ").unwrap();
+            let destinations: Vec<_> = rest.split(", ").collect();
+
+            if module == "broadcaster" {
+                (module, Module::Broadcast(destinations))
+            } else if let Some(name) = module.strip_prefix('%') {
+                (name, Module::FlipFlop(false, destinations))
+            } else if let Some(name) = module.strip_prefix('&') {
+                (name, Module::Conjuction(HashMap::new(), destinations))
+            } else {
+                panic!("Unknown module");
+            }
+        })
+        .collect();
+
+    let all_connections: Vec<_> = modules
+        .iter()
+        .flat_map(|(&source, module)| {
+            let destinations = match module {
+                Module::Broadcast(destinations) => destinations,
+                Module::FlipFlop(_, destinations) => destinations,
+                Module::Conjuction(_, destinations) => destinations,
+            };
+
+            destinations
+                .iter()
+                .map(|&dest| (source, dest))
+                .collect::<Vec<(&str, &str)>>()
+        })
+        .collect();
+
+    // build up the conjugate backlinks from the list of all connections
+    for (source, target) in all_connections {
+        if let Entry::Occupied(mut target_module) = modules.entry(target) {
+            if let Module::Conjuction(ref mut conj_inputs, _) = target_module.get_mut() {
+                conj_inputs.insert(source, false);
+            }
+        }
+    }
+
+    modules
+}
+
+#[test]
+fn part_1_example_1() {
+    let input = "broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a";
+
+    let answer = part_1(input);
+
+    assert_eq!(answer, 32000000);
+}
+
+#[test]
+fn part_2_example_2() {
+    let input = "broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output";
+
+    let answer = part_1(input);
+
+    assert_eq!(answer, 11687500);
+}
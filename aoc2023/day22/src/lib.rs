@@ -0,0 +1,182 @@
+use std::collections::HashSet;
+
+use aoc_solution::{puzzle, Solution};
+use santas_little_helpers::{point, point::Point3, vector};
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    fn part1(&self, input: &str) -> String {
+        both_parts(input).0.to_string()
+    }
+
+    fn part2(&self, input: &str) -> Option<String> {
+        Some(both_parts(input).1.to_string())
+    }
+
+    fn both_parts(&self, input: &str) -> Option<(String, String)> {
+        let (part1, part2) = both_parts(input);
+        Some((part1.to_string(), part2.to_string()))
+    }
+}
+
+puzzle!(2023, 22, Day22);
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+struct Brick {
+    points: HashSet<Point3>,
+    footprint: HashSet<Point3>,
+}
+
+fn both_parts(input: &str) -> (usize, usize) {
+    let mut bricks: Vec<_> = input
+        .lines()
+        .map(|line| {
+            let (start, end) = line.split_once("~").unwrap();
+            let start = parse_coord(start);
+            let end = parse_coord(end);
+
+            build_brick(&start, &end)
+        })
+        .collect();
+
+    // sort the bricks by the z index of the lowest point
+    bricks.sort_by(|brick_a, brick_b| {
+        let lowest_a = brick_a
+            .footprint
+            .iter()
+            .min_by(|foot_a, foot_b| foot_a[2].cmp(&foot_b[2]))
+            .unwrap()[2];
+        let lowest_b = brick_b
+            .footprint
+            .iter()
+            .min_by(|foot_a, foot_b| foot_a[2].cmp(&foot_b[2]))
+            .unwrap()[2];
+
+        lowest_a.cmp(&lowest_b)
+    });
+
+    let (settled_bricks, _) = simulate(&bricks);
+
+    let mut can_be_removed = 0;
+    let mut total_fell = 0;
+
+    for brick_to_remove in &settled_bricks {
+        let without_brick: Vec<_> = settled_bricks
+            .iter()
+            .filter(|brick| brick != &brick_to_remove)
+            .map(|brick| brick.to_owned())
+            .collect();
+
+        let (_, num_moved) = simulate(&without_brick);
+
+        if num_moved == 0 {
+            can_be_removed += 1;
+        }
+
+        total_fell += num_moved;
+    }
+
+    (can_be_removed, total_fell)
+}
+
+fn parse_coord(input: &str) -> Point3 {
+    let (x, rest) = input.split_once(",").unwrap();
+    let (y, z) = rest.split_once(",").unwrap();
+
+    point!(
+        x.parse::<isize>().unwrap(),
+        y.parse::<isize>().unwrap(),
+        z.parse::<isize>().unwrap()
+    )
+}
+
+fn build_brick(start: &Point3, end: &Point3) -> Brick {
+    let mut points = HashSet::new();
+    let mut footprint = HashSet::new();
+
+    if end[0] > start[0] {
+        for x in start[0]..=end[0] {
+            points.insert(point!(x, start[1], start[2]));
+            footprint.insert(point!(x, start[1], start[2]));
+        }
+    } else if end[1] > start[1] {
+        for y in start[1]..=end[1] {
+            points.insert(point!(start[0], y, start[2]));
+            footprint.insert(point!(start[0], y, start[2]));
+        }
+    } else if end[2] > start[2] {
+        // only upwards pointing blocks have different bases
+        for z in start[2]..=end[2] {
+            points.insert(point!(start[0], start[1], z));
+        }
+        footprint.insert(*start);
+    } else {
+        points.insert(*start);
+        footprint.insert(*start);
+    }
+
+    Brick { points, footprint }
+}
+
+fn simulate(bricks: &Vec<Brick>) -> (Vec<Brick>, usize) {
+    let mut bricks = bricks.to_owned();
+    let mut world = HashSet::new();
+
+    for brick in &bricks {
+        for &point in &brick.points {
+            world.insert(point);
+        }
+    }
+
+    let mut brick_fell = true;
+    // needs to be hashset cos a brick might move twice as part of the simulation
+    // but we only want to count it once, don't care how much it moved
+    let mut moved_bricks = HashSet::new();
+
+    while brick_fell {
+        brick_fell = false;
+
+        for (i, brick) in bricks.iter_mut().enumerate() {
+            let moved_down = move_down(brick);
+
+            if moved_down
+                .footprint
+                .iter()
+                .any(|point| world.contains(point) || point[2] == 0)
+            {
+                continue;
+            }
+
+            for point in brick.points.iter() {
+                world.remove(point);
+            }
+
+            for &point in &moved_down.points {
+                world.insert(point);
+            }
+
+            brick_fell = true;
+            moved_bricks.insert(i);
+            *brick = moved_down;
+        }
+    }
+
+    (bricks, moved_bricks.len())
+}
+
+fn move_down(brick: &Brick) -> Brick {
+    let points: HashSet<_> = brick
+        .points
+        .iter()
+        .map(|point| *point - vector!(0, 0, 1))
+        .collect();
+
+    let footprint: HashSet<_> = brick
+        .footprint
+        .iter()
+        .map(|point| *point - vector!(0, 0, 1))
+        .collect();
+
+    Brick { points, footprint }
+}
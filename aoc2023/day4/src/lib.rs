@@ -0,0 +1,69 @@
+use std::collections::HashSet;
+
+use aoc_solution::{puzzle, Solution};
+
+struct Card {
+    winners: HashSet<usize>,
+    havers: HashSet<usize>,
+}
+
+impl Card {
+    fn parse(line: &str) -> Card {
+        let (_, numbers) = line.split_once(": ").unwrap();
+        let (winners, havers) = numbers.split_once('|').unwrap();
+
+        fn parse_scores(scores: &str) -> HashSet<usize> {
+            scores
+                .split_whitespace()
+                .map(|num| num.parse::<usize>().unwrap())
+                .collect()
+        }
+
+        Card {
+            winners: parse_scores(winners),
+            havers: parse_scores(havers),
+        }
+    }
+
+    fn count_winners(&self) -> usize {
+        self.winners.intersection(&self.havers).count()
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    fn part1(&self, input: &str) -> String {
+        part_1(&input.lines().map(Card::parse).collect::<Vec<_>>()).to_string()
+    }
+
+    fn part2(&self, input: &str) -> Option<String> {
+        Some(part_2(&input.lines().map(Card::parse).collect::<Vec<_>>()).to_string())
+    }
+}
+
+puzzle!(2023, 4, Day4);
+
+fn part_1(cards: &[Card]) -> usize {
+    cards
+        .iter()
+        .map(|card| card.count_winners())
+        .filter(|winners| *winners > 0)
+        .fold(0, |total, winners| total + (2usize.pow(winners as u32 - 1)))
+}
+
+fn part_2(cards: &[Card]) -> usize {
+    let card_wins: Vec<usize> = cards.iter().map(|card| card.count_winners()).collect();
+
+    let mut card_copies = vec![1; cards.len()];
+
+    for (card_index, wins) in card_wins.iter().enumerate() {
+        for card_offset in 1..=*wins {
+            let card_copy_won = card_index + card_offset;
+            let num_copies = card_copies[card_index];
+            card_copies[card_copy_won] += num_copies
+        }
+    }
+
+    card_copies.iter().sum()
+}
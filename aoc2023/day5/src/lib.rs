@@ -0,0 +1,187 @@
+use std::{cmp::min, ops::Range};
+
+use aoc_solution::{puzzle, Solution};
+
+use regex::Regex;
+
+#[derive(Debug)]
+struct MapRange {
+    start: isize,
+    end: isize,
+    offset: isize,
+}
+
+impl MapRange {
+    fn parse(mapping: &str) -> MapRange {
+        let numbers: Vec<_> = mapping.split_whitespace().collect();
+
+        let dest_start = numbers[0].parse::<isize>().expect("failed to parse dest");
+        let source_start = numbers[1].parse::<isize>().expect("failed to parse source");
+        let length = numbers[2].parse::<isize>().expect("failed to parse length");
+
+        MapRange {
+            start: source_start,
+            end: source_start + length,
+            offset: dest_start - source_start,
+        }
+    }
+
+    fn try_map(&self, source: isize) -> Option<isize> {
+        if source >= self.start && source < self.end {
+            return Some(source + self.offset);
+        }
+        None
+    }
+}
+
+#[derive(Debug)]
+struct Mapping {
+    mappings: Vec<MapRange>,
+}
+
+impl Mapping {
+    fn parse(group: &str) -> Mapping {
+        let mut mappings = group
+            .lines()
+            .skip(1)
+            .map(MapRange::parse)
+            .collect::<Vec<MapRange>>();
+
+        mappings.sort_by_key(|a| a.start);
+
+        Mapping { mappings }
+    }
+
+    fn process(&self, source: isize) -> isize {
+        for mapping in &self.mappings {
+            if let Some(mapped_source) = mapping.try_map(source) {
+                return mapped_source;
+            }
+        }
+
+        source
+    }
+
+    fn mapped_ranges(&self, source_range: Range<isize>) -> Vec<Range<isize>> {
+        let mut new_ranges = vec![];
+        let mut current_start = source_range.start;
+
+        for mapping in self
+            .mappings
+            .iter()
+            .skip_while(|mapping| mapping.end < source_range.start)
+        {
+            if current_start < mapping.start {
+                let new_range = current_start..min(source_range.end, mapping.start);
+                new_ranges.push(new_range);
+                current_start = mapping.start;
+            }
+
+            if current_start >= mapping.end {
+                break;
+            }
+
+            let offset_start = current_start + mapping.offset;
+            let offset_end = min(source_range.end, mapping.end) + mapping.offset;
+            let new_range = offset_start..offset_end;
+            new_ranges.push(new_range);
+
+            current_start = mapping.end;
+
+            if current_start >= source_range.end {
+                break;
+            }
+        }
+
+        if current_start < source_range.end {
+            let new_range = current_start..source_range.end;
+            new_ranges.push(new_range);
+        }
+
+        new_ranges
+    }
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    fn part1(&self, input: &str) -> String {
+        part_1(input).to_string()
+    }
+
+    fn part2(&self, input: &str) -> Option<String> {
+        Some(part_2(input).to_string())
+    }
+}
+
+puzzle!(2023, 5, Day5);
+
+fn part_1(input: &str) -> isize {
+    let match_digits = Regex::new(r"\d+").expect("Failed to compile regex");
+
+    let (seeds, rest) = input.split_once("\n\n").unwrap();
+    let seeds: Vec<isize> = match_digits
+        .find_iter(seeds)
+        .map(|seed| {
+            seed.as_str()
+                .parse::<isize>()
+                .expect("Failed to parse number")
+        })
+        .collect();
+
+    let mappings: Vec<Mapping> = rest.split("\n\n").map(Mapping::parse).collect();
+
+    seeds
+        .iter()
+        .map(|seed| {
+            let mut mapped_seed = *seed;
+            for mapping in &mappings {
+                mapped_seed = mapping.process(mapped_seed);
+            }
+            mapped_seed
+        })
+        .min()
+        .unwrap()
+}
+
+fn part_2(input: &str) -> isize {
+    let match_digits = Regex::new(r"\d+").expect("Failed to compile regex");
+
+    let (seeds, rest) = input.split_once("\n\n").unwrap();
+
+    let seed_ranges: Vec<Range<isize>> = match_digits
+        .find_iter(seeds)
+        .map(|seed| {
+            seed.as_str()
+                .parse::<isize>()
+                .expect("Failed to parse number")
+        })
+        .collect::<Vec<_>>()
+        .chunks_exact(2)
+        .map(|seed_pair| seed_pair[0]..(seed_pair[0] + seed_pair[1]))
+        .collect();
+
+    let mappings: Vec<Mapping> = rest.split("\n\n").map(Mapping::parse).collect();
+
+    let mut mapped_ranges: Vec<Range<isize>> = vec![];
+
+    for seed_range in seed_ranges {
+        let mut current_ranges = vec![seed_range];
+
+        for mapping in &mappings {
+            let new_ranges = current_ranges
+                .iter()
+                .flat_map(|range| mapping.mapped_ranges(range.clone()))
+                .collect();
+            current_ranges = new_ranges;
+        }
+
+        mapped_ranges.append(&mut current_ranges);
+    }
+
+    mapped_ranges
+        .iter()
+        .min_by(|a, b| a.start.cmp(&b.start))
+        .unwrap()
+        .start
+}
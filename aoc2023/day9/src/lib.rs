@@ -0,0 +1,56 @@
+use aoc_solution::{puzzle, Solution};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    fn part1(&self, input: &str) -> String {
+        part_1(input).to_string()
+    }
+
+    fn part2(&self, input: &str) -> Option<String> {
+        Some(part_2(input).to_string())
+    }
+}
+
+puzzle!(2023, 9, Day9);
+
+fn part_1(input: &str) -> isize {
+    let sequences: Vec<Vec<_>> = input
+        .lines()
+        .map(|line| {
+            line.split_whitespace()
+                .map(|num| num.parse::<isize>().unwrap())
+                .collect()
+        })
+        .collect();
+
+    sequences.iter().map(|seq| geometric_prediction(seq)).sum()
+}
+
+fn part_2(input: &str) -> isize {
+    let sequences: Vec<Vec<_>> = input
+        .lines()
+        .map(|line| {
+            line.split_whitespace()
+                .map(|num| num.parse::<isize>().unwrap())
+                .rev()
+                .collect()
+        })
+        .collect();
+
+    sequences.iter().map(|seq| geometric_prediction(seq)).sum()
+}
+
+fn geometric_prediction(sequence: &[isize]) -> isize {
+    if sequence.iter().all(|x| x == &0) {
+        return 0;
+    }
+
+    let next_value = geometric_prediction(&find_differences(sequence));
+
+    sequence.last().unwrap() + next_value
+}
+
+fn find_differences(sequence: &[isize]) -> Vec<isize> {
+    sequence.windows(2).map(|pair| pair[1] - pair[0]).collect()
+}
@@ -0,0 +1,48 @@
+use aoc_solution::{puzzle, Solution};
+use itertools::Itertools;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    fn part1(&self, input: &str) -> String {
+        part1(input).to_string()
+    }
+
+    fn part2(&self, input: &str) -> Option<String> {
+        Some(part2(input).to_string())
+    }
+}
+
+puzzle!(2024, 1, Day1);
+
+fn part1(input: &str) -> usize {
+    let (mut list_x, mut list_y): (Vec<_>, Vec<_>) = input
+        .lines()
+        .map(|line| line.split_once("   ").unwrap())
+        .map(|(x, y)| (x.parse::<usize>().unwrap(), y.parse::<usize>().unwrap()))
+        .unzip();
+
+    list_x.sort();
+    list_y.sort();
+
+    list_x
+        .iter()
+        .zip(list_y.iter())
+        .map(|(x, y)| x.abs_diff(*y))
+        .sum()
+}
+
+fn part2(input: &str) -> usize {
+    let (list_x, list_y): (Vec<_>, Vec<_>) = input
+        .lines()
+        .map(|line| line.split_once("   ").unwrap())
+        .map(|(x, y)| (x.parse::<usize>().unwrap(), y.parse::<usize>().unwrap()))
+        .unzip();
+
+    let list_y_freq = list_y.iter().counts();
+
+    list_x
+        .iter()
+        .map(|x| x * list_y_freq.get(x).unwrap_or(&0))
+        .sum()
+}
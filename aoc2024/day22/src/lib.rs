@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+
+use aoc_solution::{puzzle, Solution};
+
+use itertools::Itertools;
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    fn part1(&self, input: &str) -> String {
+        part_1(input).to_string()
+    }
+
+    fn part2(&self, input: &str) -> Option<String> {
+        Some(part_2(input).to_string())
+    }
+}
+
+puzzle!(2024, 22, Day22);
+
+fn part_1(input: &str) -> isize {
+    input
+        .lines()
+        .map(|l| l.parse::<isize>().unwrap())
+        .map(|x| {
+            let mut secret_num = x;
+
+            for _ in 0..2000 {
+                secret_num = prune(mix(secret_num, secret_num * 64));
+                secret_num = prune(mix(secret_num, secret_num / 32));
+                secret_num = prune(mix(secret_num, secret_num * 2048));
+            }
+
+            secret_num
+        })
+        .sum()
+}
+
+fn part_2(input: &str) -> isize {
+    let mut scores = HashMap::new();
+
+    for price in input.lines().map(|l| l.parse::<isize>().unwrap()) {
+        let mut secret_num = price;
+        let mut nums = Vec::new();
+
+        for _ in 0..2000 {
+            secret_num = prune(mix(secret_num, secret_num * 64));
+            secret_num = prune(mix(secret_num, secret_num / 32));
+            secret_num = prune(mix(secret_num, secret_num * 2048));
+
+            nums.push(secret_num % 10);
+        }
+
+        let mut buy_results = HashMap::new();
+        for (n5, n4, n3, n2, n1) in nums.iter().rev().tuple_windows() {
+            let changes = (n2 - n1, n3 - n2, n4 - n3, n5 - n4);
+            buy_results.insert(changes, n5);
+        }
+
+        for (changes, result) in buy_results {
+            *scores.entry(changes).or_insert(0) += result;
+        }
+    }
+
+    scores.into_values().max().unwrap()
+}
+
+fn mix(secret: isize, x: isize) -> isize {
+    secret ^ x
+}
+
+fn prune(secret: isize) -> isize {
+    secret.rem_euclid(16777216)
+}
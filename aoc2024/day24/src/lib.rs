@@ -0,0 +1,231 @@
+use std::collections::{HashMap, VecDeque};
+
+use aoc_solution::{puzzle, Solution};
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    fn part1(&self, input: &str) -> String {
+        part_1(input).to_string()
+    }
+
+    fn part2(&self, input: &str) -> Option<String> {
+        Some(part_2(input))
+    }
+}
+
+puzzle!(2024, 24, Day24);
+
+#[derive(Debug, Clone)]
+enum Type {
+    And,
+    Or,
+    Xor,
+    None,
+}
+
+#[derive(Debug, Clone)]
+struct Gate<'a> {
+    a: Option<&'a str>,
+    b: Option<&'a str>,
+    gate_type: Type,
+    value: Option<bool>,
+}
+
+impl Default for Gate<'_> {
+    fn default() -> Self {
+        Self {
+            a: None,
+            b: None,
+            gate_type: Type::None,
+            value: None,
+        }
+    }
+}
+
+fn part_1(input: &str) -> usize {
+    let mut circuit = parse_circuit(input);
+
+    run_circuit(&mut circuit);
+
+    let mut zs = circuit
+        .keys()
+        .filter(|k| k.starts_with('z'))
+        .collect::<Vec<_>>();
+    zs.sort();
+    let bits = zs.iter().rev().fold(String::new(), |mut b, z| {
+        let bit = match circuit[**z].value {
+            Some(true) => '1',
+            Some(false) => '0',
+            None => panic!(),
+        };
+        b.push(bit);
+        b
+    });
+
+    usize::from_str_radix(&bits, 2).unwrap()
+}
+
+// Observations
+// - Every x and y input should go to an AND and an XOR
+// - Other than first and last z bits, they should all be XOR
+// - Sibling of z bit should be AND which is fed by OR and XOR
+//
+// Possibly wrong:
+// z10, gpr
+// krs, cpm
+// ghp, z33
+// z21, nks
+//
+// Note to future me:
+// Building with the graph feature writes two graphviz dot files, unchanged.dot and modified.dot
+// Each node has its name and type, z nodes are coloured red to spot easily, input nodes are
+// blue
+// The modified graph will colour the changed pairs green so they can be double checked
+fn part_2(input: &str) -> String {
+    let original_circuit = parse_circuit(input);
+    let mut modified = original_circuit.clone();
+
+    if cfg!(feature = "graph") {
+        output_graph(&original_circuit, "unchanged.dot", &[]);
+    }
+
+    swap_values(&mut modified, "z10", "gpr");
+    swap_values(&mut modified, "krs", "cpm");
+    swap_values(&mut modified, "ghp", "z33");
+    swap_values(&mut modified, "z21", "nks");
+    let mut modifications = vec!["z10", "gpr", "krs", "cpm", "ghp", "z33", "z21", "nks"];
+
+    if cfg!(feature = "graph") {
+        output_graph(&modified, "modified.dot", &modifications);
+    }
+
+    modifications.sort();
+    modifications.join(",")
+}
+
+fn swap_values<'a>(map: &mut HashMap<&'a str, Gate>, a: &'a str, b: &'a str) {
+    let v1 = map.get_mut(&a).map(std::mem::take);
+    let v2 = map.get_mut(&b).map(std::mem::take);
+
+    if let (Some(v1), Some(v2)) = (v1, v2) {
+        map.insert(a, v2);
+        map.insert(b, v1);
+    }
+}
+
+fn output_graph(circuit: &HashMap<&str, Gate<'_>>, filename: &str, modified: &[&str]) {
+    let mut dot = String::from("digraph Day24Graph {\n");
+
+    for (n, node) in circuit.iter() {
+        let node_type = match node.gate_type {
+            Type::And => "AND",
+            Type::Or => "OR",
+            Type::Xor => "XOR",
+            Type::None => "in",
+        };
+
+        let mut attributes = format!(r#"shape=circle,label="{n}: {node_type}""#);
+
+        if n.starts_with('z') {
+            attributes.push_str(",color=coral1,style=filled");
+        }
+
+        if n.starts_with('x') || n.starts_with('y') {
+            attributes.push_str(",color=cornflowerblue,style=filled");
+        }
+
+        if modified.contains(n) {
+            attributes.push_str(",color=darkseagreen1,style=filled");
+        }
+
+        dot.push_str(&format!("  {n}[{attributes}]\n"));
+    }
+
+    for (n, node) in circuit.iter() {
+        for from in [node.a, node.b].into_iter().flatten() {
+            dot.push_str(&format!("  {from} -> {n}\n"));
+        }
+    }
+
+    dot.push('}');
+
+    std::fs::write(filename, dot).unwrap();
+}
+
+fn run_circuit(circuit: &mut HashMap<&str, Gate<'_>>) {
+    let mut queue = VecDeque::new();
+    circuit.keys().for_each(|k| queue.push_back(*k));
+
+    while let Some(gate_key) = queue.pop_front() {
+        let gate = circuit.get(gate_key).unwrap();
+        if gate.value.is_some() {
+            continue;
+        }
+
+        let dep_a = circuit.get(gate.a.unwrap()).unwrap();
+        let dep_b = &circuit[gate.b.unwrap()];
+
+        if dep_a.value.is_none() || dep_b.value.is_none() {
+            queue.push_back(gate_key);
+            continue;
+        }
+
+        let val_a = dep_a.value.unwrap();
+        let val_b = dep_b.value.unwrap();
+
+        let gate = circuit.get_mut(gate_key).unwrap();
+        gate.value = gate_logic(gate, val_a, val_b);
+    }
+}
+
+fn gate_logic(gate: &Gate<'_>, val_a: bool, val_b: bool) -> Option<bool> {
+    match (&gate.gate_type, val_a, val_b) {
+        (Type::And, a, b) => Some(a & b),
+        (Type::Or, a, b) => Some(a | b),
+        (Type::Xor, a, b) => Some(a ^ b),
+        (Type::None, _, _) => None,
+    }
+}
+
+fn parse_circuit(input: &str) -> HashMap<&str, Gate<'_>> {
+    let (wires, raw_circuit) = input.split_once("\n\n").unwrap();
+
+    let mut circuit = HashMap::new();
+    for chunk in raw_circuit.lines() {
+        let (a, rest) = chunk.split_once(' ').unwrap();
+        let (gate_type, rest) = rest.split_once(' ').unwrap();
+        let (b, rest) = rest.split_once(' ').unwrap();
+        let (_, gate_name) = rest.split_once(' ').unwrap();
+
+        circuit.entry(a).or_default();
+        circuit.entry(b).or_default();
+
+        let gate = Gate {
+            a: Some(a),
+            b: Some(b),
+            gate_type: match gate_type {
+                "AND" => Type::And,
+                "OR" => Type::Or,
+                "XOR" => Type::Xor,
+                _ => panic!(),
+            },
+            value: None,
+        };
+
+        *circuit.entry(gate_name).or_default() = gate;
+    }
+
+    for wire in wires.lines() {
+        let (gate, signal) = wire.split_once(": ").unwrap();
+        let signal = match signal {
+            "0" => false,
+            "1" => true,
+            _ => panic!(),
+        };
+
+        circuit.get_mut(gate).unwrap().value = Some(signal);
+    }
+
+    circuit
+}
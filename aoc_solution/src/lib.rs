@@ -0,0 +1,148 @@
+use std::{env, fmt, fs, io, process::ExitCode, time::Instant};
+
+/// A day's puzzle. Answers are strings so each day can keep whatever type suits it
+pub trait Solution {
+    fn part1(&self, input: &str) -> String;
+
+    /// Will return none for days with only one part, like day 25
+    fn part2(&self, _input: &str) -> Option<String> {
+        None
+    }
+
+    /// For days that find both answers in one go, so running both parts doesn't do the work
+    /// twice. Will return none to solve the parts one at a time
+    fn both_parts(&self, _input: &str) -> Option<(String, String)> {
+        None
+    }
+}
+
+/// A solution along with which puzzle it solves and where its input lives. Day crates declare
+/// one with `puzzle!` and the runner finds them by year and day
+#[derive(Clone, Copy)]
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    pub solution: &'static dyn Solution,
+    /// Absolute path, only read when the puzzle is run so nothing needs inputs to build
+    pub input: &'static str,
+}
+
+impl Puzzle {
+    pub fn read_input(&self) -> io::Result<String> {
+        fs::read_to_string(self.input)
+    }
+
+    /// Will return none if the puzzle doesn't have that part
+    pub fn solve(&self, part: u8, input: &str) -> Option<String> {
+        match part {
+            1 => Some(self.solution.part1(input)),
+            2 => self.solution.part2(input),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Debug for Puzzle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Puzzle")
+            .field("year", &self.year)
+            .field("day", &self.day)
+            .field("input", &self.input)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Declares the crate's `PUZZLE`. The input path is relative to the crate root and defaults to
+/// `src/input.txt`
+#[macro_export]
+macro_rules! puzzle {
+    ($year:literal, $day:literal, $solution:expr) => {
+        $crate::puzzle!($year, $day, $solution, "src/input.txt");
+    };
+    ($year:literal, $day:literal, $solution:expr, $input:literal) => {
+        pub const PUZZLE: $crate::Puzzle = $crate::Puzzle {
+            year: $year,
+            day: $day,
+            solution: &$solution,
+            input: concat!(env!("CARGO_MANIFEST_DIR"), "/", $input),
+        };
+    };
+}
+
+/// Solve a part and print the answer with how long it took. Returns false if there is no
+/// such part
+pub fn print_part(puzzle: &Puzzle, part: u8, input: &str) -> bool {
+    let start = Instant::now();
+    let Some(answer) = puzzle.solve(part, input) else {
+        return false;
+    };
+
+    println!("Part {part}: {answer} ({:.2?})", start.elapsed());
+    true
+}
+
+/// Solve every part the puzzle has and print the answers
+pub fn print_parts(puzzle: &Puzzle, input: &str) {
+    let start = Instant::now();
+    if let Some((part1, part2)) = puzzle.solution.both_parts(input) {
+        println!("Part 1: {part1}");
+        println!("Part 2: {part2} ({:.2?} for both)", start.elapsed());
+        return;
+    }
+
+    for part in 1..=2 {
+        print_part(puzzle, part, input);
+    }
+}
+
+/// Solve every part of the puzzle on its input, for the day crates' mains
+pub fn main(puzzle: &Puzzle) -> ExitCode {
+    let input = match puzzle.read_input() {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("could not read {}: {err}", puzzle.input);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    print_parts(puzzle, &input);
+
+    ExitCode::SUCCESS
+}
+
+/// For crates holding a whole year of puzzles, the day to run is the first argument
+pub fn main_for_day(puzzles: &[Puzzle]) -> ExitCode {
+    let day = env::args().nth(1).and_then(|day| day.parse::<u8>().ok());
+    match puzzles.iter().find(|puzzle| Some(puzzle.day) == day) {
+        Some(puzzle) => main(puzzle),
+        None => {
+            let days: Vec<String> = puzzles.iter().map(|p| p.day.to_string()).collect();
+            eprintln!("pick a day to run, one of {}", days.join(", "));
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Lengths;
+
+    impl Solution for Lengths {
+        fn part1(&self, input: &str) -> String {
+            input.len().to_string()
+        }
+    }
+
+    puzzle!(2015, 25, Lengths, "inputs/lengths.txt");
+
+    #[test]
+    fn declares_puzzle() {
+        assert_eq!((PUZZLE.year, PUZZLE.day), (2015, 25));
+        assert!(PUZZLE.input.ends_with("aoc_solution/inputs/lengths.txt"));
+        assert_eq!(PUZZLE.solve(1, "abc"), Some("3".to_string()));
+        assert_eq!(PUZZLE.solve(2, "abc"), None);
+        assert_eq!(PUZZLE.solve(3, "abc"), None);
+    }
+}
@@ -11,7 +11,7 @@ pub struct Coord {
 #[macro_export]
 macro_rules! coord {
     ($x:expr, $y:expr) => {
-        crate::coord::Coord {
+        $crate::coord::Coord {
             x: $x as isize,
             y: $y as isize,
         }
@@ -46,7 +46,7 @@ impl Delta {
 #[macro_export]
 macro_rules! delta {
     ($dx:expr, $dy:expr) => {
-        crate::coord::Delta {
+        $crate::coord::Delta {
             dx: $dx as isize,
             dy: $dy as isize,
         }
@@ -0,0 +1,208 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Where a sequence of states starts repeating. The state after prefix_len steps is the first one
+/// seen again, and it comes back every cycle_len steps after that
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub prefix_len: usize,
+    pub cycle_len: usize,
+}
+
+impl Cycle {
+    /// The earliest step with the same state as step n
+    /// Example: cycle.equivalent_step(1_000_000_000)
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.prefix_len {
+            n
+        } else {
+            self.prefix_len + (n - self.prefix_len) % self.cycle_len
+        }
+    }
+}
+
+/// Hash every state until one repeats. Uses memory for every state but needs the fewest calls to
+/// step
+pub fn find_cycle<S, F>(start: S, step: F) -> Cycle
+where
+    S: Clone + Hash + Eq,
+    F: FnMut(&S) -> S,
+{
+    let (cycle, _states) = walk(start, step, None);
+    cycle.expect("only stops early with a target")
+}
+
+/// The state after n steps, jumping ahead once a cycle is found so n can be huge
+pub fn state_at<S, F>(start: S, step: F, n: usize) -> S
+where
+    S: Clone + Hash + Eq,
+    F: FnMut(&S) -> S,
+{
+    let (cycle, mut states) = walk(start, step, Some(n));
+    let index = match cycle {
+        Some(cycle) => cycle.equivalent_step(n),
+        None => n, // reached n before anything repeated
+    };
+
+    states.swap_remove(index)
+}
+
+/// Step until a state repeats or until there are states up to target, returning every state seen
+fn walk<S, F>(start: S, mut step: F, target: Option<usize>) -> (Option<Cycle>, Vec<S>)
+where
+    S: Clone + Hash + Eq,
+    F: FnMut(&S) -> S,
+{
+    let mut seen = HashMap::from([(start.clone(), 0)]);
+    let mut states = vec![start];
+
+    loop {
+        if target.is_some_and(|target| states.len() > target) {
+            return (None, states);
+        }
+
+        let next = step(states.last().expect("always has start"));
+        let i = states.len();
+
+        if let Some(&first) = seen.get(&next) {
+            let cycle = Cycle {
+                prefix_len: first,
+                cycle_len: i - first,
+            };
+            return (Some(cycle), states);
+        }
+
+        seen.insert(next.clone(), i);
+        states.push(next);
+    }
+}
+
+/// Floyd's tortoise and hare, only ever holds a few states at once
+pub fn floyd<S, F>(start: S, mut step: F) -> Cycle
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> S,
+{
+    // Find a meeting point inside the cycle
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+
+    // The start of the cycle is the same distance from the start as from the meeting point
+    let mut prefix_len = 0;
+    tortoise = start;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix_len += 1;
+    }
+
+    let mut cycle_len = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        cycle_len += 1;
+    }
+
+    Cycle {
+        prefix_len,
+        cycle_len,
+    }
+}
+
+/// Brent's algorithm, like Floyd's but with fewer calls to step
+pub fn brent<S, F>(start: S, mut step: F) -> Cycle
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> S,
+{
+    // Teleport the tortoise to the hare at each power of two until they meet
+    let mut power = 1;
+    let mut cycle_len = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == cycle_len {
+            tortoise = hare.clone();
+            power *= 2;
+            cycle_len = 0;
+        }
+        hare = step(&hare);
+        cycle_len += 1;
+    }
+
+    // Start the hare a whole cycle ahead, they meet at the start of the cycle
+    tortoise = start.clone();
+    hare = start;
+    for _ in 0..cycle_len {
+        hare = step(&hare);
+    }
+
+    let mut prefix_len = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix_len += 1;
+    }
+
+    Cycle {
+        prefix_len,
+        cycle_len,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 2 ...
+    fn step(x: &usize) -> usize {
+        if *x == 5 {
+            2
+        } else {
+            x + 1
+        }
+    }
+
+    const EXPECTED: Cycle = Cycle {
+        prefix_len: 2,
+        cycle_len: 4,
+    };
+
+    #[test]
+    fn all_methods_agree() {
+        assert_eq!(find_cycle(0, step), EXPECTED);
+        assert_eq!(floyd(0, step), EXPECTED);
+        assert_eq!(brent(0, step), EXPECTED);
+    }
+
+    #[test]
+    fn cycle_from_the_start() {
+        let expected = Cycle {
+            prefix_len: 0,
+            cycle_len: 3,
+        };
+        let step = |x: &u8| (x + 1) % 3;
+
+        assert_eq!(find_cycle(0, step), expected);
+        assert_eq!(floyd(0, step), expected);
+        assert_eq!(brent(0, step), expected);
+    }
+
+    #[test]
+    fn equivalent_step() {
+        assert_eq!(EXPECTED.equivalent_step(1), 1);
+        assert_eq!(EXPECTED.equivalent_step(6), 2);
+        assert_eq!(EXPECTED.equivalent_step(9), 5);
+    }
+
+    #[test]
+    fn state_at_extrapolates() {
+        assert_eq!(state_at(0, step, 1), 1);
+        assert_eq!(state_at(0, step, 4), 4);
+        assert_eq!(state_at(0, step, 1_000_000_000), 2 + (1_000_000_000 - 2) % 4);
+    }
+}
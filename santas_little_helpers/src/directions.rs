@@ -44,3 +44,126 @@ impl Direction {
         }
     }
 }
+
+/// The eight compass points, north is up (towards negative y)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CompassDirection {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl CompassDirection {
+    pub fn delta(&self) -> Delta {
+        match self {
+            CompassDirection::North => delta!(0, -1),
+            CompassDirection::NorthEast => delta!(1, -1),
+            CompassDirection::East => delta!(1, 0),
+            CompassDirection::SouthEast => delta!(1, 1),
+            CompassDirection::South => delta!(0, 1),
+            CompassDirection::SouthWest => delta!(-1, 1),
+            CompassDirection::West => delta!(-1, 0),
+            CompassDirection::NorthWest => delta!(-1, -1),
+        }
+    }
+
+    /// Clockwise from north
+    pub fn iterator() -> impl Iterator<Item = CompassDirection> {
+        [
+            CompassDirection::North,
+            CompassDirection::NorthEast,
+            CompassDirection::East,
+            CompassDirection::SouthEast,
+            CompassDirection::South,
+            CompassDirection::SouthWest,
+            CompassDirection::West,
+            CompassDirection::NorthWest,
+        ]
+        .into_iter()
+    }
+
+    /// All eight deltas clockwise from north
+    pub fn deltas() -> impl Iterator<Item = Delta> {
+        Self::iterator().map(|direction| direction.delta())
+    }
+
+    /// Only the four diagonals
+    pub fn diagonals() -> impl Iterator<Item = CompassDirection> {
+        [
+            CompassDirection::NorthEast,
+            CompassDirection::SouthEast,
+            CompassDirection::SouthWest,
+            CompassDirection::NorthWest,
+        ]
+        .into_iter()
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        let delta = self.delta();
+        delta.dx != 0 && delta.dy != 0
+    }
+
+    /// Rotate 45 degrees clockwise
+    pub fn turn_right(self) -> Self {
+        match self {
+            CompassDirection::North => CompassDirection::NorthEast,
+            CompassDirection::NorthEast => CompassDirection::East,
+            CompassDirection::East => CompassDirection::SouthEast,
+            CompassDirection::SouthEast => CompassDirection::South,
+            CompassDirection::South => CompassDirection::SouthWest,
+            CompassDirection::SouthWest => CompassDirection::West,
+            CompassDirection::West => CompassDirection::NorthWest,
+            CompassDirection::NorthWest => CompassDirection::North,
+        }
+    }
+
+    /// Rotate 45 degrees anti-clockwise
+    pub fn turn_left(self) -> Self {
+        match self {
+            CompassDirection::North => CompassDirection::NorthWest,
+            CompassDirection::NorthEast => CompassDirection::North,
+            CompassDirection::East => CompassDirection::NorthEast,
+            CompassDirection::SouthEast => CompassDirection::East,
+            CompassDirection::South => CompassDirection::SouthEast,
+            CompassDirection::SouthWest => CompassDirection::South,
+            CompassDirection::West => CompassDirection::SouthWest,
+            CompassDirection::NorthWest => CompassDirection::West,
+        }
+    }
+}
+
+impl From<Direction> for CompassDirection {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => CompassDirection::North,
+            Direction::Down => CompassDirection::South,
+            Direction::Left => CompassDirection::West,
+            Direction::Right => CompassDirection::East,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compass_turns_round_trip() {
+        for direction in CompassDirection::iterator() {
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_ne!(direction.turn_right().is_diagonal(), direction.is_diagonal());
+        }
+    }
+
+    #[test]
+    fn compass_matches_orthogonal_direction() {
+        for direction in Direction::iterator() {
+            assert_eq!(CompassDirection::from(direction).delta(), direction.delta());
+        }
+    }
+}
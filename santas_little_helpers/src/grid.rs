@@ -1,64 +1,154 @@
-use crate::{coord::Coord, directions::Direction, coord};
+use std::{
+    collections::HashMap,
+    fmt,
+    ops::{Index, IndexMut},
+    slice::{Chunks, ChunksMut},
+};
 
-#[derive(Debug)]
+use crate::{
+    coord,
+    coord::{Coord, Delta},
+    directions::{CompassDirection, Direction},
+};
+
+/// A rectangular grid stored row by row in a single contiguous vec
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Grid<T> {
     pub width: usize,
     pub height: usize,
-    pub grid: Vec<Vec<T>>,
+    tiles: Vec<T>,
 }
 
-impl<T: PartialEq> Grid<T> {
+impl<T> Grid<T> {
     pub fn new(grid: Vec<Vec<T>>) -> Self {
-        Self {
-            width: grid[0].len(),
-            height: grid.len(),
-            grid,
-        }
+        Self::from_vecs(grid)
     }
 
     pub fn init(init_tile: T, width: usize, height: usize) -> Self
     where
         T: Clone,
     {
-        let mut rows = Vec::new();
-        for _ in 0..height {
-            let row = vec![init_tile.clone(); width];
-            rows.push(row);
+        Self {
+            tiles: vec![init_tile; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Flatten rows into a grid, will panic if the rows are not all the same width
+    pub fn from_vecs(grid: Vec<Vec<T>>) -> Self {
+        let width = grid.first().map_or(0, Vec::len);
+        let height = grid.len();
+
+        let mut tiles = Vec::with_capacity(width * height);
+        for (y, row) in grid.into_iter().enumerate() {
+            assert_eq!(row.len(), width, "row {y} is not the same width as the first");
+            tiles.extend(row);
         }
 
         Self {
-            grid: rows,
             width,
             height,
+            tiles,
         }
     }
 
-    pub fn from_vecs(grid: Vec<Vec<T>>) -> Self {
+    /// Build from an already flattened vec of tiles in reading order, will panic if the length
+    /// doesn't match the dimensions
+    pub fn from_flat(tiles: Vec<T>, width: usize, height: usize) -> Self {
+        assert_eq!(tiles.len(), width * height, "tiles don't fit a {width}x{height} grid");
+
         Self {
-            width: grid[0].len(),
-            height: grid.len(),
-            grid,
+            width,
+            height,
+            tiles,
+        }
+    }
+
+    /// Parse puzzle text where each char is converted into a tile with `TryFrom<char>`
+    /// Example: Grid::<char>::parse("#.\n.#")
+    pub fn parse(input: &str) -> Result<Self, ParseGridError<T::Error>>
+    where
+        T: TryFrom<char>,
+    {
+        Self::parse_with(input, T::try_from)
+    }
+
+    /// Parse puzzle text using a closure to convert each char into a tile. Every row must be the
+    /// same width, and the first failing char is reported with its 1 based line and column
+    pub fn parse_with<E, F>(input: &str, mut parse_tile: F) -> Result<Self, ParseGridError<E>>
+    where
+        F: FnMut(char) -> Result<T, E>,
+    {
+        let mut tiles = Vec::new();
+        let mut width = 0;
+        let mut height = 0;
+
+        // Only line endings are stripped, spaces can be tiles
+        for (y, line) in input.trim_end_matches(['\n', '\r']).lines().enumerate() {
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            let row_start = tiles.len();
+            for (x, char) in line.chars().enumerate() {
+                let tile = parse_tile(char).map_err(|error| ParseGridError::BadTile {
+                    line: y + 1,
+                    column: x + 1,
+                    char,
+                    error,
+                })?;
+                tiles.push(tile);
+            }
+
+            let row_width = tiles.len() - row_start;
+            if y == 0 {
+                width = row_width;
+            } else if row_width != width {
+                return Err(ParseGridError::RaggedRow {
+                    line: y + 1,
+                    expected: width,
+                    found: row_width,
+                });
+            }
+
+            height += 1;
+        }
+
+        if tiles.is_empty() {
+            return Err(ParseGridError::Empty);
+        }
+
+        Ok(Self::from_flat(tiles, width, height))
+    }
+
+    /// Position of the coord in the flat storage, none if out of bounds
+    fn index_of(&self, coord: &Coord) -> Option<usize> {
+        if self.in_bounds(coord) {
+            Some(coord.y as usize * self.width + coord.x as usize)
+        } else {
+            None
         }
     }
 
     /// Get at coordinate, will panic if out of bounds
     pub fn get(&self, coord: &Coord) -> &T {
-        &self.grid[coord.y as usize][coord.x as usize]
+        &self[*coord]
     }
 
     pub fn get_mut(&mut self, coord: Coord) -> &mut T {
-        &mut self.grid[coord.y as usize][coord.x as usize]
+        &mut self[coord]
     }
 
     pub fn set(&mut self, coord: Coord, tile: T) {
-        self.grid[coord.y as usize][coord.x as usize] = tile;
+        self[coord] = tile;
     }
 
     /// Will return none if out of bounds
     pub fn try_get(&self, coord: &Coord) -> Option<&T> {
-        let y: usize = coord.y.try_into().ok()?;
-        let x: usize = coord.x.try_into().ok()?;
-        self.grid.get(y)?.get(x)
+        self.index_of(coord).map(|i| &self.tiles[i])
+    }
+
+    /// Will return none if out of bounds
+    pub fn try_get_mut(&mut self, coord: &Coord) -> Option<&mut T> {
+        self.index_of(coord).map(|i| &mut self.tiles[i])
     }
 
     pub fn in_bounds(&self, coord: &Coord) -> bool {
@@ -68,8 +158,48 @@ impl<T: PartialEq> Grid<T> {
             && coord.y < self.height as isize
     }
 
+    /// The tiles of row y from left to right, will panic if out of bounds
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "row {y} out of bounds");
+        &self.tiles[y * self.width..(y + 1) * self.width]
+    }
+
+    /// The tiles of column x from top to bottom, will panic if out of bounds
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        assert!(x < self.width, "column {x} out of bounds");
+        self.tiles.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    /// Each row in order from the top
+    pub fn rows(&self) -> Chunks<'_, T> {
+        self.tiles.chunks(self.width.max(1))
+    }
+
+    /// Each row in order from the top, mutably
+    pub fn rows_mut(&mut self) -> ChunksMut<'_, T> {
+        self.tiles.chunks_mut(self.width.max(1))
+    }
+
+    /// Every tile with its coordinate in reading order
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> + '_ {
+        let width = self.width;
+        self.tiles
+            .iter()
+            .enumerate()
+            .map(move |(i, tile)| (coord!(i % width, i / width), tile))
+    }
+
+    /// Consume the grid into every tile with its coordinate in reading order
+    pub fn into_tiles(self) -> impl Iterator<Item = (Coord, T)> {
+        let width = self.width;
+        self.tiles
+            .into_iter()
+            .enumerate()
+            .map(move |(i, tile)| (coord!(i % width, i / width), tile))
+    }
+
     /// An iterator over all neighbours that are in bounds. No checks about the tile are made
-    pub fn neighbours<'a>(&'a self, current: Coord) -> impl Iterator<Item = Coord> + 'a {
+    pub fn neighbours(&self, current: Coord) -> impl Iterator<Item = Coord> + '_ {
         let mut neighbours = Vec::new();
         for delta in Direction::deltas() {
             let applied = current + delta;
@@ -81,44 +211,405 @@ impl<T: PartialEq> Grid<T> {
         neighbours.into_iter()
     }
 
+    /// An iterator over all in bounds neighbours including diagonals
+    pub fn neighbours8(&self, current: Coord) -> impl Iterator<Item = Coord> + '_ {
+        CompassDirection::deltas()
+            .map(move |delta| current + delta)
+            .filter(|applied| self.in_bounds(applied))
+    }
+
+    /// Walk from a coord repeatedly applying delta, yielding each coord until leaving the grid.
+    /// The starting coord is not included, and a zero delta will never end
+    pub fn ray(&self, from: Coord, delta: Delta) -> impl Iterator<Item = Coord> + '_ {
+        std::iter::successors(Some(from + delta), move |&current| Some(current + delta))
+            .take_while(|current| self.in_bounds(current))
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
     /// An iterator over all in bound neibhbours that match the required tile value
     pub fn matching_neighbours<'a>(&'a self, current: Coord, tile: T) -> impl Iterator<Item = Coord> + 'a {
         self.neighbours(current)
-            .filter(move |x| self.get(&x) == &tile)
+            .filter(move |x| self.get(x) == &tile)
     }
 
     /// Scan in reading order from 0,0 and optionally return the first coord that matches the
     /// requirement
     pub fn find_first(&self, seeking: &T) -> Option<Coord> {
-        for (y, row) in self.grid.iter().enumerate() {
-            for (x, tile) in row.iter().enumerate() {
-                if tile == seeking {
-                    return Some(coord!(x, y));
-                }
-            }
+        self.tiles
+            .iter()
+            .position(|tile| tile == seeking)
+            .map(|i| coord!(i % self.width, i / self.width))
+    }
+}
+
+/// A tile that can be drawn as, and read from, a single char
+pub trait GridTile: Sized {
+    fn to_char(&self) -> char;
+
+    /// None if the char isn't a known tile
+    fn from_char(char: char) -> Option<Self>;
+}
+
+impl GridTile for char {
+    fn to_char(&self) -> char {
+        *self
+    }
+
+    fn from_char(char: char) -> Option<Self> {
+        Some(char)
+    }
+}
+
+/// Walls and floors, '#' is true
+impl GridTile for bool {
+    fn to_char(&self) -> char {
+        if *self {
+            '#'
+        } else {
+            '.'
         }
+    }
+
+    fn from_char(char: char) -> Option<Self> {
+        match char {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        }
+    }
+}
+
+impl<T: GridTile> Grid<T> {
+    /// Parse puzzle text with `GridTile::from_char`
+    pub fn parse_tiles(input: &str) -> Result<Self, ParseGridError<UnknownTile>> {
+        Self::parse_with(input, |char| T::from_char(char).ok_or(UnknownTile))
+    }
+
+    /// Draw the grid with some coords replaced by other chars, such as a path
+    /// Example: grid.overlay().mark_all(&path, 'O')
+    pub fn overlay(&self) -> Overlay<'_, T> {
+        Overlay {
+            grid: self,
+            marks: HashMap::new(),
+        }
+    }
+}
 
-        None
+/// The grid drawn one row per line
+impl<T: GridTile> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.overlay().fmt(f)
     }
 }
 
-impl<T: PartialEq> From<Vec<Vec<T>>> for Grid<T> {
+/// A borrowed grid with chars drawn over the top of some of its tiles when displayed
+pub struct Overlay<'a, T> {
+    grid: &'a Grid<T>,
+    marks: HashMap<Coord, char>,
+}
+
+impl<T> Overlay<'_, T> {
+    /// Draw char at coord instead of its tile, later marks replace earlier ones
+    pub fn mark(mut self, coord: Coord, char: char) -> Self {
+        self.marks.insert(coord, char);
+        self
+    }
+
+    /// Draw char at every coord
+    pub fn mark_all<'c>(mut self, coords: impl IntoIterator<Item = &'c Coord>, char: char) -> Self {
+        for coord in coords {
+            self.marks.insert(*coord, char);
+        }
+        self
+    }
+}
+
+impl<T: GridTile> fmt::Display for Overlay<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.grid.rows().enumerate() {
+            let line: String = row
+                .iter()
+                .enumerate()
+                .map(|(x, tile)| match self.marks.get(&coord!(x, y)) {
+                    Some(mark) => *mark,
+                    None => tile.to_char(),
+                })
+                .collect();
+            writeln!(f, "{line}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    /// Will panic if out of bounds
+    fn index(&self, coord: Coord) -> &T {
+        let i = self
+            .index_of(&coord)
+            .unwrap_or_else(|| panic!("{coord:?} out of bounds"));
+        &self.tiles[i]
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    /// Will panic if out of bounds
+    fn index_mut(&mut self, coord: Coord) -> &mut T {
+        let i = self
+            .index_of(&coord)
+            .unwrap_or_else(|| panic!("{coord:?} out of bounds"));
+        &mut self.tiles[i]
+    }
+}
+
+impl<T> From<Vec<Vec<T>>> for Grid<T> {
     fn from(vecs: Vec<Vec<T>>) -> Self {
         Grid::from_vecs(vecs)
     }
 }
 
+/// Why puzzle text couldn't be turned into a grid. Lines and columns are 1 based
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseGridError<E> {
+    /// No rows, or a first row with no tiles
+    Empty,
+    /// A row that isn't the same width as the first
+    RaggedRow {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// The tile parser rejected a char
+    BadTile {
+        line: usize,
+        column: usize,
+        char: char,
+        error: E,
+    },
+}
+
+impl<E: fmt::Display> fmt::Display for ParseGridError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseGridError::Empty => write!(f, "grid has no tiles"),
+            ParseGridError::RaggedRow {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {line} is {found} tiles wide but expected {expected}"
+            ),
+            ParseGridError::BadTile {
+                line,
+                column,
+                char,
+                error,
+            } => write!(f, "bad tile {char:?} at line {line} column {column}: {error}"),
+        }
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> std::error::Error for ParseGridError<E> {}
+
+/// The error for a char that `GridTile::from_char` didn't recognise
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownTile;
+
+impl fmt::Display for UnknownTile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown tile")
+    }
+}
+
+impl std::error::Error for UnknownTile {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[derive(Debug, PartialEq, Eq)]
+    enum Tile {
+        Wall,
+        Floor,
+    }
+
+    fn parse_tile(char: char) -> Result<Tile, String> {
+        match char {
+            '#' => Ok(Tile::Wall),
+            '.' => Ok(Tile::Floor),
+            _ => Err(format!("unknown tile {char}")),
+        }
+    }
+
     #[test]
     fn grid_move_direction_in_bounds() {
-        todo!()
+        let grid = Grid::init('.', 3, 3);
+        let moved = coord!(1, 1) + Direction::Up.delta();
+
+        assert_eq!(moved, coord!(1, 0));
+        assert!(grid.in_bounds(&moved));
+        assert_eq!(grid.try_get(&moved), Some(&'.'));
     }
 
     #[test]
     fn grid_move_out_of_bounds() {
-        todo!()
+        let grid = Grid::init('.', 3, 3);
+        let moved = coord!(0, 0) + Direction::Left.delta();
+
+        assert!(!grid.in_bounds(&moved));
+        assert_eq!(grid.try_get(&moved), None);
+    }
+
+    #[test]
+    fn parse_chars() {
+        let grid = Grid::<char>::parse("#.\n.#\n").unwrap();
+
+        assert_eq!(grid.width, 2);
+        assert_eq!(grid.height, 2);
+        assert_eq!(grid.get(&coord!(1, 0)), &'.');
+        assert_eq!(grid.get(&coord!(1, 1)), &'#');
+    }
+
+    #[test]
+    fn parse_with_tiles() {
+        let grid = Grid::parse_with("##\n#.", parse_tile).unwrap();
+
+        assert_eq!(grid.get(&coord!(0, 1)), &Tile::Wall);
+        assert_eq!(grid.get(&coord!(1, 1)), &Tile::Floor);
+    }
+
+    #[test]
+    fn parse_keeps_trailing_space_tiles() {
+        let grid = Grid::parse_with("# \r\n  \n", Ok::<char, ()>).unwrap();
+
+        assert_eq!((grid.width, grid.height), (2, 2));
+        assert_eq!(grid.get(&coord!(1, 0)), &' ');
+        assert_eq!(grid.get(&coord!(1, 1)), &' ');
+    }
+
+    #[test]
+    fn parse_with_reports_bad_tile() {
+        let err = Grid::parse_with("##\n#?", parse_tile).unwrap_err();
+
+        assert_eq!(
+            err,
+            ParseGridError::BadTile {
+                line: 2,
+                column: 2,
+                char: '?',
+                error: "unknown tile ?".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_rejects_ragged_rows() {
+        let err = Grid::<char>::parse("###\n##\n###").unwrap_err();
+
+        assert_eq!(
+            err,
+            ParseGridError::RaggedRow {
+                line: 2,
+                expected: 3,
+                found: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn flat_index_does_not_wrap_rows() {
+        let grid = Grid::from_vecs(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        assert_eq!(grid[coord!(2, 1)], 6);
+        assert_eq!(grid.try_get(&coord!(3, 0)), None);
+        assert_eq!(grid.try_get(&coord!(-1, 1)), None);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let mut grid = Grid::from_vecs(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), vec![3, 6]);
+
+        for row in grid.rows_mut() {
+            row.reverse();
+        }
+
+        assert_eq!(grid.rows().collect::<Vec<_>>(), vec![&[3, 2, 1], &[6, 5, 4]]);
+        assert_eq!(grid.find_first(&4), Some(coord!(2, 1)));
+    }
+
+    #[test]
+    fn neighbours8_in_bounds() {
+        let grid = Grid::init('.', 3, 3);
+
+        assert_eq!(grid.neighbours8(coord!(1, 1)).count(), 8);
+        assert_eq!(grid.neighbours8(coord!(0, 0)).count(), 3);
+        assert_eq!(grid.neighbours8(coord!(2, 1)).count(), 5);
+    }
+
+    #[test]
+    fn ray_until_out_of_bounds() {
+        let grid = Grid::init('.', 4, 3);
+        let ray = grid.ray(coord!(0, 0), CompassDirection::SouthEast.delta());
+
+        assert_eq!(ray.collect::<Vec<_>>(), vec![coord!(1, 1), coord!(2, 2)]);
+        assert_eq!(grid.ray(coord!(0, 0), Direction::Up.delta()).count(), 0);
+    }
+
+    impl GridTile for Tile {
+        fn to_char(&self) -> char {
+            match self {
+                Tile::Wall => '#',
+                Tile::Floor => '.',
+            }
+        }
+
+        fn from_char(char: char) -> Option<Self> {
+            parse_tile(char).ok()
+        }
+    }
+
+    #[test]
+    fn display_round_trips_parse_tiles() {
+        let text = "##.\n.#.\n";
+        let grid = Grid::<Tile>::parse_tiles(text).unwrap();
+
+        assert_eq!(grid.to_string(), text);
+        assert_eq!(
+            Grid::<Tile>::parse_tiles("#x").unwrap_err(),
+            ParseGridError::BadTile {
+                line: 1,
+                column: 2,
+                char: 'x',
+                error: UnknownTile,
+            }
+        );
+    }
+
+    #[test]
+    fn overlay_marks_path() {
+        let grid = Grid::init(false, 3, 2);
+        let path = [coord!(0, 0), coord!(1, 0), coord!(1, 1)];
+
+        let drawn = grid.overlay().mark_all(&path, 'O').mark(coord!(2, 1), 'E');
+        assert_eq!(drawn.to_string(), "OO.\n.OE\n");
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_vecs_rejects_ragged_rows() {
+        Grid::from_vecs(vec![vec![1, 2], vec![3]]);
+    }
+
+    #[test]
+    fn parse_rejects_empty() {
+        assert_eq!(Grid::<char>::parse("").unwrap_err(), ParseGridError::Empty);
+        assert_eq!(Grid::<char>::parse("\n\n").unwrap_err(), ParseGridError::Empty);
     }
 }
@@ -1,3 +1,10 @@
 pub mod coord;
+pub mod cycle;
 pub mod grid;
+pub mod math;
 pub mod directions;
+pub mod point;
+#[cfg(feature = "render")]
+pub mod render;
+pub mod search;
+pub mod sparse_grid;
@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+
+/// Greatest common divisor, gcd(0, 0) is 0
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Lowest common multiple, none if it overflows
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Greatest common divisor of every number, 0 if there are none
+pub fn gcd_all(numbers: impl IntoIterator<Item = u64>) -> u64 {
+    numbers.into_iter().fold(0, gcd)
+}
+
+/// Lowest common multiple of every number, 1 if there are none and none if it overflows
+/// Example: lcm_all(periods.iter().copied())
+pub fn lcm_all(numbers: impl IntoIterator<Item = u64>) -> Option<u64> {
+    numbers.into_iter().try_fold(1, lcm)
+}
+
+/// Extended Euclid, returns (g, x, y) where a * x + b * y = g = gcd(a, b) and g is never negative
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The x in 0..modulus where a * x = 1 (mod modulus), none if a and modulus aren't coprime
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// a * b (mod modulus) without overflowing
+pub fn mul_mod(a: i64, b: i64, modulus: i64) -> i64 {
+    (a as i128 * b as i128).rem_euclid(modulus as i128) as i64
+}
+
+/// base ^ exp (mod modulus) by repeated squaring
+pub fn pow_mod(base: i64, mut exp: u64, modulus: i64) -> i64 {
+    let mut result = 1 % modulus;
+    let mut base = base.rem_euclid(modulus);
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exp >>= 1;
+    }
+
+    result
+}
+
+/// Chinese remainder theorem over (residue, modulus) pairs, the moduli don't need to be coprime.
+/// Returns (x, m) where every solution is x (mod m) and x is in 0..m, none if the congruences
+/// contradict each other or m overflows
+/// Example: crt([(0, 7), (12, 13)])
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>) -> Option<(i64, i64)> {
+    congruences
+        .into_iter()
+        .try_fold((0, 1), |(x, m), (residue, modulus)| {
+            merge_congruence(x, m, residue, modulus)
+        })
+}
+
+/// Combine x = a1 (mod m1) and x = a2 (mod m2) into one congruence
+fn merge_congruence(a1: i64, m1: i64, a2: i64, m2: i64) -> Option<(i64, i64)> {
+    let (g, p, _) = extended_gcd(m1, m2);
+    let diff = a2 - a1;
+    if diff % g != 0 {
+        return None;
+    }
+
+    let lcm = i64::try_from(m1 as i128 / g as i128 * m2 as i128).ok()?;
+
+    // m1 * p = g (mod m2) so stepping a1 by m1 * k where k = diff / g * p lands on a2 (mod m2)
+    let k = (diff as i128 / g as i128 * p as i128).rem_euclid((m2 / g) as i128);
+    let x = (a1 as i128 + m1 as i128 * k).rem_euclid(lcm as i128);
+
+    Some((x as i64, lcm))
+}
+
+/// Baby step giant step, the smallest x where base ^ x = target (mod modulus). Needs base and
+/// modulus to be coprime, so a prime modulus is ideal. None if there is no answer
+pub fn discrete_log(base: i64, target: i64, modulus: i64) -> Option<u64> {
+    let target = target.rem_euclid(modulus);
+    let steps = (modulus as f64).sqrt().ceil() as u64;
+
+    // Baby steps, keeping the smallest exponent for each value
+    let mut table = HashMap::new();
+    let mut value = 1 % modulus;
+    for j in 0..steps {
+        table.entry(value).or_insert(j);
+        value = mul_mod(value, base, modulus);
+    }
+
+    // Giant steps, multiplying the target by base ^ -steps each time
+    let giant = mod_inverse(pow_mod(base, steps, modulus), modulus)?;
+    let mut gamma = target;
+    for i in 0..steps {
+        if let Some(j) = table.get(&gamma) {
+            return Some(i * steps + j);
+        }
+        gamma = mul_mod(gamma, giant, modulus);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(gcd_all([12, 18, 27]), 3);
+        assert_eq!(lcm_all([2, 3, 4, 5]), Some(60));
+        assert_eq!(lcm_all([u64::MAX, u64::MAX - 1]), None);
+    }
+
+    #[test]
+    fn extended_gcd_identity() {
+        for (a, b) in [(240, 46), (-35, 15), (17, 0), (0, -4)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a.unsigned_abs(), b.unsigned_abs()) as i64);
+            assert_eq!(a * x + b * y, g);
+        }
+    }
+
+    #[test]
+    fn inverses() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+    }
+
+    #[test]
+    fn pow_mod_large() {
+        assert_eq!(pow_mod(2, 10, 1000), 24);
+        assert_eq!(pow_mod(7, 0, 13), 1);
+        assert_eq!(pow_mod(3, 1_000_000_006, 1_000_000_007), 1); // fermat
+    }
+
+    #[test]
+    fn crt_coprime() {
+        // 2020 day 13 example, t = 1068781
+        let buses = [(7, 0), (13, 1), (59, 4), (31, 6), (19, 7)];
+        let congruences = buses.map(|(id, offset): (i64, i64)| (-offset, id));
+
+        assert_eq!(crt(congruences), Some((1068781, 7 * 13 * 59 * 31 * 19)));
+    }
+
+    #[test]
+    fn crt_not_coprime() {
+        assert_eq!(crt([(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+    }
+
+    #[test]
+    fn discrete_log_handshake() {
+        // 2020 day 25 example loop sizes
+        assert_eq!(discrete_log(7, 5764801, 20201227), Some(8));
+        assert_eq!(discrete_log(7, 17807724, 20201227), Some(11));
+        assert_eq!(discrete_log(2, 3, 7), None);
+    }
+}
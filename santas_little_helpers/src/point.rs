@@ -0,0 +1,323 @@
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+
+use crate::coord::{Coord, Delta};
+
+/// A position in N dimensional space
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<const N: usize>(pub [isize; N]);
+
+/// A displacement in N dimensional space, the difference between two points
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vector<const N: usize>(pub [isize; N]);
+
+pub type Point3 = Point<3>;
+pub type Point4 = Point<4>;
+pub type Vector3 = Vector<3>;
+pub type Vector4 = Vector<4>;
+
+/// Will cast every component to isize, will panic if this is bad
+/// Example: point!(3, 2, -1)
+#[macro_export]
+macro_rules! point {
+    ($($component:expr),+ $(,)?) => {
+        $crate::point::Point([$($component as isize),+])
+    };
+}
+
+/// Will cast every component to isize, will panic if this is bad
+/// Example: vector!(0, -1, 1)
+#[macro_export]
+macro_rules! vector {
+    ($($component:expr),+ $(,)?) => {
+        $crate::point::Vector([$($component as isize),+])
+    };
+}
+
+impl<const N: usize> Point<N> {
+    pub fn new(components: [isize; N]) -> Self {
+        Self(components)
+    }
+
+    pub fn origin() -> Self {
+        Self([0; N])
+    }
+
+    /// Compute the manhattan (taxi cab) distance between two points
+    pub fn manhattan_dist(&self, other: &Point<N>) -> usize {
+        (*other - *self).manhattan_len()
+    }
+
+    /// Compute the chebyshev (king's move) distance between two points
+    pub fn chebyshev_dist(&self, other: &Point<N>) -> usize {
+        (*other - *self).chebyshev_len()
+    }
+
+    /// All 3^N - 1 points touching this one, including diagonals
+    pub fn neighbours(&self) -> impl Iterator<Item = Point<N>> + '_ {
+        Vector::<N>::unit_cube().map(move |delta| *self + delta)
+    }
+
+    /// The 2N points touching this one along a single axis
+    pub fn orthogonal_neighbours(&self) -> impl Iterator<Item = Point<N>> + '_ {
+        Vector::<N>::axes().map(move |delta| *self + delta)
+    }
+}
+
+impl<const N: usize> Vector<N> {
+    pub fn new(components: [isize; N]) -> Self {
+        Self(components)
+    }
+
+    pub fn zero() -> Self {
+        Self([0; N])
+    }
+
+    pub fn manhattan_len(&self) -> usize {
+        self.0.iter().map(|c| c.unsigned_abs()).sum()
+    }
+
+    pub fn chebyshev_len(&self) -> usize {
+        self.0.iter().map(|c| c.unsigned_abs()).max().unwrap_or(0)
+    }
+
+    pub fn dot(&self, other: &Vector<N>) -> isize {
+        self.0.iter().zip(other.0.iter()).map(|(a, b)| a * b).sum()
+    }
+
+    /// Every vector with components in -1..=1 except zero, the offsets to all neighbours
+    pub fn unit_cube() -> impl Iterator<Item = Vector<N>> {
+        (0..3_usize.pow(N as u32))
+            .map(|mut n| {
+                let mut components = [0; N];
+                for component in components.iter_mut() {
+                    *component = (n % 3) as isize - 1;
+                    n /= 3;
+                }
+                Vector(components)
+            })
+            .filter(|delta| *delta != Vector::zero())
+    }
+
+    /// A step of one forwards and backwards along each axis
+    pub fn axes() -> impl Iterator<Item = Vector<N>> {
+        (0..N).flat_map(|axis| {
+            [-1, 1].into_iter().map(move |step| {
+                let mut components = [0; N];
+                components[axis] = step;
+                Vector(components)
+            })
+        })
+    }
+}
+
+impl Vector<3> {
+    pub fn cross(&self, other: &Vector<3>) -> Vector<3> {
+        let [ax, ay, az] = self.0;
+        let [bx, by, bz] = other.0;
+        Vector([ay * bz - az * by, az * bx - ax * bz, ax * by - ay * bx])
+    }
+}
+
+impl<const N: usize> Index<usize> for Point<N> {
+    type Output = isize;
+
+    fn index(&self, axis: usize) -> &isize {
+        &self.0[axis]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for Point<N> {
+    fn index_mut(&mut self, axis: usize) -> &mut isize {
+        &mut self.0[axis]
+    }
+}
+
+impl<const N: usize> Index<usize> for Vector<N> {
+    type Output = isize;
+
+    fn index(&self, axis: usize) -> &isize {
+        &self.0[axis]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for Vector<N> {
+    fn index_mut(&mut self, axis: usize) -> &mut isize {
+        &mut self.0[axis]
+    }
+}
+
+impl<const N: usize> Add<Vector<N>> for Point<N> {
+    type Output = Point<N>;
+
+    fn add(mut self, delta: Vector<N>) -> Point<N> {
+        self += delta;
+        self
+    }
+}
+
+impl<const N: usize> AddAssign<Vector<N>> for Point<N> {
+    fn add_assign(&mut self, delta: Vector<N>) {
+        for (c, d) in self.0.iter_mut().zip(delta.0) {
+            *c += d;
+        }
+    }
+}
+
+impl<const N: usize> Sub<Vector<N>> for Point<N> {
+    type Output = Point<N>;
+
+    fn sub(mut self, delta: Vector<N>) -> Point<N> {
+        self -= delta;
+        self
+    }
+}
+
+impl<const N: usize> SubAssign<Vector<N>> for Point<N> {
+    fn sub_assign(&mut self, delta: Vector<N>) {
+        for (c, d) in self.0.iter_mut().zip(delta.0) {
+            *c -= d;
+        }
+    }
+}
+
+impl<const N: usize> Sub<Point<N>> for Point<N> {
+    type Output = Vector<N>;
+
+    fn sub(self, other: Point<N>) -> Vector<N> {
+        Vector(std::array::from_fn(|i| self.0[i] - other.0[i]))
+    }
+}
+
+impl<const N: usize> Add<Vector<N>> for Vector<N> {
+    type Output = Vector<N>;
+
+    fn add(mut self, other: Vector<N>) -> Vector<N> {
+        self += other;
+        self
+    }
+}
+
+impl<const N: usize> AddAssign<Vector<N>> for Vector<N> {
+    fn add_assign(&mut self, other: Vector<N>) {
+        for (c, d) in self.0.iter_mut().zip(other.0) {
+            *c += d;
+        }
+    }
+}
+
+impl<const N: usize> Sub<Vector<N>> for Vector<N> {
+    type Output = Vector<N>;
+
+    fn sub(mut self, other: Vector<N>) -> Vector<N> {
+        self -= other;
+        self
+    }
+}
+
+impl<const N: usize> SubAssign<Vector<N>> for Vector<N> {
+    fn sub_assign(&mut self, other: Vector<N>) {
+        for (c, d) in self.0.iter_mut().zip(other.0) {
+            *c -= d;
+        }
+    }
+}
+
+impl<const N: usize> Neg for Vector<N> {
+    type Output = Vector<N>;
+
+    fn neg(self) -> Vector<N> {
+        Vector(self.0.map(|c| -c))
+    }
+}
+
+impl<const N: usize> Mul<isize> for Vector<N> {
+    type Output = Vector<N>;
+
+    fn mul(self, scale: isize) -> Vector<N> {
+        Vector(self.0.map(|c| c * scale))
+    }
+}
+
+impl From<Coord> for Point<2> {
+    fn from(coord: Coord) -> Self {
+        Point([coord.x, coord.y])
+    }
+}
+
+impl From<Point<2>> for Coord {
+    fn from(point: Point<2>) -> Self {
+        Coord::new(point.0[0], point.0[1])
+    }
+}
+
+impl From<Delta> for Vector<2> {
+    fn from(delta: Delta) -> Self {
+        Vector([delta.dx, delta.dy])
+    }
+}
+
+impl From<Vector<2>> for Delta {
+    fn from(vector: Vector<2>) -> Self {
+        Delta::new(vector.0[0], vector.0[1])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point_macro_casts() {
+        let z = 4_usize;
+        assert_eq!(point!(1, -2, z), Point([1, -2, 4]));
+        assert_eq!(vector!(0, 1), Vector([0, 1]));
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = point!(1, 2, 3);
+        let b = point!(4, 0, -1);
+
+        assert_eq!(b - a, vector!(3, -2, -4));
+        assert_eq!(a + (b - a), b);
+        assert_eq!(b - (b - a), a);
+        assert_eq!(-vector!(1, -1) * 3, vector!(-3, 3));
+    }
+
+    #[test]
+    fn distances() {
+        let a = point!(1, 2, 3, 4);
+        let b = point!(2, 0, 3, -1);
+
+        assert_eq!(a.manhattan_dist(&b), 8);
+        assert_eq!(a.chebyshev_dist(&b), 5);
+    }
+
+    #[test]
+    fn dot_and_cross() {
+        let x = vector!(1, 0, 0);
+        let y = vector!(0, 1, 0);
+
+        assert_eq!(x.dot(&y), 0);
+        assert_eq!(x.cross(&y), vector!(0, 0, 1));
+        assert_eq!(y.cross(&x), vector!(0, 0, -1));
+    }
+
+    #[test]
+    fn neighbour_counts() {
+        assert_eq!(point!(0, 0).neighbours().count(), 8);
+        assert_eq!(point!(0, 0, 0).neighbours().count(), 26);
+        assert_eq!(point!(0, 0, 0, 0).neighbours().count(), 80);
+        assert_eq!(point!(0, 0, 0).orthogonal_neighbours().count(), 6);
+        assert!(point!(5, 5, 5).neighbours().all(|n| n.chebyshev_dist(&point!(5, 5, 5)) == 1));
+    }
+
+    #[test]
+    fn converts_with_coord() {
+        let coord = Coord::new(3, -2);
+        let point: Point<2> = coord.into();
+
+        assert_eq!(point, point!(3, -2));
+        assert_eq!(Coord::from(point), coord);
+    }
+}
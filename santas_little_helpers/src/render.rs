@@ -0,0 +1,273 @@
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+use crate::{coord, coord::Coord, grid::Grid, sparse_grid::SparseGrid};
+
+pub type Rgb = [u8; 3];
+
+/// Drawn for coords a frame covers that the grid doesn't
+pub const BACKGROUND: Rgb = [0, 0, 0];
+
+/// Anything with tiles at coords that can be drawn as an image
+pub trait Drawable<T> {
+    /// The top left and bottom right corners (inclusive) of everything there is to draw
+    fn bounds(&self) -> Option<(Coord, Coord)>;
+
+    /// None if there is no tile at coord
+    fn tile(&self, coord: &Coord) -> Option<&T>;
+}
+
+impl<T> Drawable<T> for Grid<T> {
+    fn bounds(&self) -> Option<(Coord, Coord)> {
+        if self.width == 0 || self.height == 0 {
+            return None;
+        }
+
+        Some((coord!(0, 0), coord!(self.width - 1, self.height - 1)))
+    }
+
+    fn tile(&self, coord: &Coord) -> Option<&T> {
+        self.try_get(coord)
+    }
+}
+
+impl<T> Drawable<T> for SparseGrid<T> {
+    fn bounds(&self) -> Option<(Coord, Coord)> {
+        self.bounds()
+    }
+
+    fn tile(&self, coord: &Coord) -> Option<&T> {
+        Some(self.get(coord))
+    }
+}
+
+/// A window onto a drawable, scale is the width and height in pixels of each tile
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Viewport {
+    pub origin: Coord,
+    pub width: usize,
+    pub height: usize,
+    pub scale: usize,
+}
+
+impl Viewport {
+    /// Covers the current bounds of the drawable, none if there is nothing to draw
+    pub fn fit<T>(image: &impl Drawable<T>, scale: usize) -> Option<Self> {
+        let (min, max) = image.bounds()?;
+
+        Some(Self {
+            origin: min,
+            width: (max.x - min.x + 1) as usize,
+            height: (max.y - min.y + 1) as usize,
+            scale,
+        })
+    }
+
+    pub fn pixel_width(&self) -> usize {
+        self.width * self.scale
+    }
+
+    pub fn pixel_height(&self) -> usize {
+        self.height * self.scale
+    }
+
+    /// One colour per pixel in reading order
+    fn rasterise<T>(&self, image: &impl Drawable<T>, colour: &impl Fn(&T) -> Rgb) -> Vec<Rgb> {
+        let mut pixels = Vec::with_capacity(self.pixel_width() * self.pixel_height());
+
+        for y in 0..self.height {
+            let row: Vec<Rgb> = (0..self.width)
+                .map(|x| {
+                    let coord = coord!(self.origin.x + x as isize, self.origin.y + y as isize);
+                    image.tile(&coord).map_or(BACKGROUND, colour)
+                })
+                .collect();
+
+            for _ in 0..self.scale {
+                for rgb in row.iter() {
+                    pixels.extend(std::iter::repeat_n(*rgb, self.scale));
+                }
+            }
+        }
+
+        pixels
+    }
+}
+
+/// Save everything in the drawable as a png with each tile scale pixels square
+pub fn write_png<T>(
+    path: impl AsRef<Path>,
+    image: &impl Drawable<T>,
+    scale: usize,
+    colour: impl Fn(&T) -> Rgb,
+) -> io::Result<()> {
+    let viewport = Viewport::fit(image, scale)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "nothing to draw"))?;
+
+    encode_png(File::create(path)?, &viewport, image, colour)
+}
+
+/// Encode the viewport onto the drawable as a png into any writer
+pub fn encode_png<T>(
+    writer: impl Write,
+    viewport: &Viewport,
+    image: &impl Drawable<T>,
+    colour: impl Fn(&T) -> Rgb,
+) -> io::Result<()> {
+    let pixels = viewport.rasterise(image, &colour);
+
+    let mut encoder = png::Encoder::new(
+        BufWriter::new(writer),
+        viewport.pixel_width() as u32,
+        viewport.pixel_height() as u32,
+    );
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(pixels.as_flattened())?;
+
+    Ok(())
+}
+
+/// Records frames of a simulation into a looping animated gif. Every frame draws the same
+/// viewport so the grid can change size between frames
+pub struct GifRecorder<W: Write> {
+    encoder: gif::Encoder<W>,
+    viewport: Viewport,
+    delay: u16,
+}
+
+impl GifRecorder<BufWriter<File>> {
+    /// Create a gif file, delay_ms is how long each frame is shown for
+    pub fn create(path: impl AsRef<Path>, viewport: Viewport, delay_ms: u16) -> io::Result<Self> {
+        Self::new(BufWriter::new(File::create(path)?), viewport, delay_ms)
+    }
+}
+
+impl<W: Write> GifRecorder<W> {
+    pub fn new(writer: W, viewport: Viewport, delay_ms: u16) -> io::Result<Self> {
+        let too_big = |_| io::Error::new(io::ErrorKind::InvalidInput, "viewport too big for a gif");
+        let width = u16::try_from(viewport.pixel_width()).map_err(too_big)?;
+        let height = u16::try_from(viewport.pixel_height()).map_err(too_big)?;
+
+        let mut encoder = gif::Encoder::new(writer, width, height, &[]).map_err(io::Error::other)?;
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(io::Error::other)?;
+
+        Ok(Self {
+            encoder,
+            viewport,
+            // gif delays are in hundredths of a second
+            delay: delay_ms / 10,
+        })
+    }
+
+    /// Draw the viewport onto the drawable as the next frame. Each frame can use at most 256
+    /// colours
+    pub fn record<T>(
+        &mut self,
+        image: &impl Drawable<T>,
+        colour: impl Fn(&T) -> Rgb,
+    ) -> io::Result<()> {
+        let pixels = self.viewport.rasterise(image, &colour);
+
+        let mut palette: HashMap<Rgb, u8> = HashMap::new();
+        let mut palette_bytes = Vec::new();
+        let mut indices = Vec::with_capacity(pixels.len());
+
+        for rgb in pixels {
+            let index = match palette.get(&rgb) {
+                Some(index) => *index,
+                None => {
+                    let index = u8::try_from(palette.len()).map_err(|_| {
+                        io::Error::new(io::ErrorKind::InvalidInput, "more than 256 colours in frame")
+                    })?;
+                    palette.insert(rgb, index);
+                    palette_bytes.extend(rgb);
+                    index
+                }
+            };
+            indices.push(index);
+        }
+
+        let mut frame = gif::Frame::from_palette_pixels(
+            self.viewport.pixel_width() as u16,
+            self.viewport.pixel_height() as u16,
+            indices,
+            palette_bytes,
+            None,
+        );
+        frame.delay = self.delay;
+
+        self.encoder.write_frame(&frame).map_err(io::Error::other)
+    }
+
+    /// Finish the gif and hand back the writer
+    pub fn finish(self) -> io::Result<W> {
+        self.encoder.into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn colour(wall: &bool) -> Rgb {
+        if *wall {
+            [255, 255, 255]
+        } else {
+            [0, 0, 128]
+        }
+    }
+
+    #[test]
+    fn rasterise_scales_tiles() {
+        let grid = Grid::from_vecs(vec![vec![true, false]]);
+        let viewport = Viewport::fit(&grid, 2).unwrap();
+        let pixels = viewport.rasterise(&grid, &colour);
+
+        assert_eq!((viewport.pixel_width(), viewport.pixel_height()), (4, 2));
+        assert_eq!(
+            pixels,
+            [[255, 255, 255], [255, 255, 255], [0, 0, 128], [0, 0, 128]].repeat(2)
+        );
+    }
+
+    #[test]
+    fn png_has_signature() {
+        let grid = Grid::init(true, 3, 2);
+        let viewport = Viewport::fit(&grid, 1).unwrap();
+
+        let mut bytes = Vec::new();
+        encode_png(&mut bytes, &viewport, &grid, colour).unwrap();
+
+        assert!(bytes.starts_with(b"\x89PNG\r\n\x1a\n"));
+    }
+
+    #[test]
+    fn gif_records_frames_of_growing_sparse_grid() {
+        let viewport = Viewport {
+            origin: coord!(-1, -1),
+            width: 3,
+            height: 3,
+            scale: 1,
+        };
+        let mut recorder = GifRecorder::new(Vec::new(), viewport, 100).unwrap();
+
+        let mut sparse = SparseGrid::new(false);
+        for i in 0..3 {
+            sparse.set(coord!(i - 1, i - 1), true);
+            recorder.record(&sparse, colour).unwrap();
+        }
+
+        let bytes = recorder.finish().unwrap();
+        assert!(bytes.starts_with(b"GIF89a"));
+        assert_eq!(bytes.last(), Some(&0x3b)); // gif trailer
+    }
+}
@@ -0,0 +1,321 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+/// Everything reachable from the start of a search, with the best distance to each state and
+/// every predecessor that reaches it at that distance
+#[derive(Debug, Clone)]
+pub struct Search<S> {
+    pub start: S,
+    pub distances: HashMap<S, usize>,
+    pub predecessors: HashMap<S, Vec<S>>,
+}
+
+impl<S: Clone + Hash + Eq> Search<S> {
+    /// Best distance from the start, none if unreachable
+    pub fn distance(&self, state: &S) -> Option<usize> {
+        self.distances.get(state).copied()
+    }
+
+    /// One shortest path from the start to goal inclusive, none if unreachable
+    pub fn path_to(&self, goal: &S) -> Option<Vec<S>> {
+        if !self.distances.contains_key(goal) {
+            return None;
+        }
+
+        let mut path = vec![goal.clone()];
+        let mut current = goal;
+        while *current != self.start {
+            let Some(previous) = self.predecessors.get(current).and_then(|p| p.first()) else {
+                break;
+            };
+            path.push(previous.clone());
+            current = previous;
+        }
+
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every state that lies on any shortest path from the start to goal, including both ends.
+    /// Empty if goal is unreachable
+    pub fn on_shortest_paths(&self, goal: &S) -> HashSet<S> {
+        let mut seen = HashSet::new();
+        if !self.distances.contains_key(goal) {
+            return seen;
+        }
+
+        let mut work = vec![goal.clone()];
+        while let Some(state) = work.pop() {
+            if !seen.insert(state.clone()) {
+                continue;
+            }
+
+            if let Some(previous) = self.predecessors.get(&state) {
+                work.extend(previous.iter().cloned());
+            }
+        }
+
+        seen
+    }
+}
+
+/// Breadth first search where every step costs one, exploring everything reachable from start
+pub fn bfs<S, I, F>(start: S, mut successors: F) -> Search<S>
+where
+    S: Clone + Hash + Eq,
+    I: IntoIterator<Item = S>,
+    F: FnMut(&S) -> I,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut predecessors: HashMap<S, Vec<S>> = HashMap::new();
+    let mut queue = VecDeque::from([start.clone()]);
+
+    while let Some(state) = queue.pop_front() {
+        let next_dist = distances[&state] + 1;
+
+        for next in successors(&state) {
+            match distances.get(&next) {
+                Some(&dist) if dist < next_dist => continue,
+                Some(_) => {}
+                None => {
+                    distances.insert(next.clone(), next_dist);
+                    queue.push_back(next.clone());
+                }
+            }
+            predecessors.entry(next).or_default().push(state.clone());
+        }
+    }
+
+    Search {
+        start,
+        distances,
+        predecessors,
+    }
+}
+
+/// Dijkstra's shortest paths where successors yields each next state with the cost to step there,
+/// exploring everything reachable from start
+pub fn dijkstra<S, I, F>(start: S, mut successors: F) -> Search<S>
+where
+    S: Clone + Hash + Eq,
+    I: IntoIterator<Item = (S, usize)>,
+    F: FnMut(&S) -> I,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut predecessors: HashMap<S, Vec<S>> = HashMap::new();
+
+    // States live in a vec so the heap only needs to order by cost and not by state
+    let mut states = vec![start.clone()];
+    let mut heap = BinaryHeap::from([Reverse((0, 0))]);
+
+    while let Some(Reverse((cost, id))) = heap.pop() {
+        let state = states[id].clone();
+        if cost > distances[&state] {
+            continue; // stale entry, a cheaper route was already found
+        }
+
+        for (next, step) in successors(&state) {
+            // Zero cost steps can lead back to the start, which must never get a predecessor
+            if next == start {
+                continue;
+            }
+
+            let next_cost = cost + step;
+            match distances.get(&next) {
+                Some(&dist) if dist < next_cost => continue,
+                Some(&dist) if dist == next_cost => {
+                    let previous = predecessors.entry(next).or_default();
+                    if !previous.contains(&state) {
+                        previous.push(state.clone());
+                    }
+                    continue;
+                }
+                _ => {}
+            }
+
+            distances.insert(next.clone(), next_cost);
+            predecessors.insert(next.clone(), vec![state.clone()]);
+            heap.push(Reverse((next_cost, states.len())));
+            states.push(next);
+        }
+    }
+
+    Search {
+        start,
+        distances,
+        predecessors,
+    }
+}
+
+/// A* search from start until a state passes is_goal, returning the path to it inclusive and its
+/// cost. The heuristic must never overestimate the remaining cost or the path may not be the
+/// shortest
+pub fn astar<S, I, F, H, G>(
+    start: S,
+    mut successors: F,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Option<(Vec<S>, usize)>
+where
+    S: Clone + Hash + Eq,
+    I: IntoIterator<Item = (S, usize)>,
+    F: FnMut(&S) -> I,
+    H: FnMut(&S) -> usize,
+    G: FnMut(&S) -> bool,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut came_from: HashMap<S, S> = HashMap::new();
+
+    let mut states = vec![start.clone()];
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+
+    while let Some(Reverse((_estimate, cost, id))) = heap.pop() {
+        let state = states[id].clone();
+        if cost > distances[&state] {
+            continue;
+        }
+
+        if is_goal(&state) {
+            let mut path = vec![state.clone()];
+            let mut current = &state;
+            while let Some(previous) = came_from.get(current) {
+                path.push(previous.clone());
+                current = previous;
+            }
+            path.reverse();
+
+            return Some((path, cost));
+        }
+
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            if distances.get(&next).is_some_and(|&dist| dist <= next_cost) {
+                continue;
+            }
+
+            distances.insert(next.clone(), next_cost);
+            came_from.insert(next.clone(), state.clone());
+            heap.push(Reverse((next_cost + heuristic(&next), next_cost, states.len())));
+            states.push(next);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{coord, coord::Coord, grid::Grid};
+
+    fn maze() -> Grid<char> {
+        Grid::parse("...#\n.#..\n...#\n#...").unwrap()
+    }
+
+    fn open_neighbours(grid: &Grid<char>, pos: &Coord) -> Vec<Coord> {
+        grid.matching_neighbours(*pos, '.').collect()
+    }
+
+    #[test]
+    fn bfs_distances_and_path() {
+        let grid = maze();
+        let search = bfs(coord!(0, 0), |pos| open_neighbours(&grid, pos));
+
+        assert_eq!(search.distance(&coord!(3, 3)), Some(6));
+        assert_eq!(search.distance(&coord!(3, 0)), None);
+
+        let path = search.path_to(&coord!(3, 3)).unwrap();
+        assert_eq!(path.len(), 7);
+        assert_eq!(path.first(), Some(&coord!(0, 0)));
+        assert_eq!(path.last(), Some(&coord!(3, 3)));
+    }
+
+    #[test]
+    fn bfs_all_shortest_paths() {
+        let grid = Grid::init('.', 2, 2);
+        let search = bfs(coord!(0, 0), |pos| open_neighbours(&grid, pos));
+
+        // Both ways around the square are equally short
+        assert_eq!(search.on_shortest_paths(&coord!(1, 1)).len(), 4);
+        assert_eq!(search.on_shortest_paths(&coord!(1, 0)).len(), 2);
+    }
+
+    #[test]
+    fn dijkstra_prefers_cheaper_detour() {
+        // a -> b costs 10 directly but 3 via c
+        let edges = HashMap::from([
+            ('a', vec![('b', 10), ('c', 1)]),
+            ('c', vec![('b', 2)]),
+            ('b', vec![]),
+        ]);
+        let search = dijkstra('a', |s| edges[s].clone());
+
+        assert_eq!(search.distance(&'b'), Some(3));
+        assert_eq!(search.path_to(&'b'), Some(vec!['a', 'c', 'b']));
+    }
+
+    #[test]
+    fn dijkstra_keeps_tied_predecessors() {
+        let edges = HashMap::from([
+            ('a', vec![('b', 1), ('c', 2)]),
+            ('b', vec![('d', 2)]),
+            ('c', vec![('d', 1)]),
+            ('d', vec![]),
+        ]);
+        let search = dijkstra('a', |s| edges[s].clone());
+
+        assert_eq!(search.distance(&'d'), Some(3));
+        assert_eq!(
+            search.on_shortest_paths(&'d'),
+            HashSet::from(['a', 'b', 'c', 'd'])
+        );
+    }
+
+    #[test]
+    fn dijkstra_zero_cost_cycle() {
+        let edges = HashMap::from([
+            ('a', vec![('b', 0)]),
+            ('b', vec![('a', 0), ('c', 1)]),
+            ('c', vec![]),
+        ]);
+        let search = dijkstra('a', |s| edges[s].clone());
+
+        assert_eq!(search.distance(&'b'), Some(0));
+        assert_eq!(search.predecessors.get(&'a'), None);
+        assert_eq!(search.path_to(&'b'), Some(vec!['a', 'b']));
+        assert_eq!(search.path_to(&'c'), Some(vec!['a', 'b', 'c']));
+        assert_eq!(search.path_to(&'a'), Some(vec!['a']));
+    }
+
+    #[test]
+    fn astar_finds_shortest_path() {
+        let grid = maze();
+        let goal = coord!(3, 3);
+        let (path, cost) = astar(
+            coord!(0, 0),
+            |pos| open_neighbours(&grid, pos).into_iter().map(|n| (n, 1)),
+            |pos| pos.manhattan_dist(&goal),
+            |pos| *pos == goal,
+        )
+        .unwrap();
+
+        assert_eq!(cost, 6);
+        assert_eq!(path.len(), 7);
+    }
+
+    #[test]
+    fn astar_unreachable() {
+        let grid = maze();
+        let result = astar(
+            coord!(0, 0),
+            |pos| open_neighbours(&grid, pos).into_iter().map(|n| (n, 1)),
+            |_| 0,
+            |pos| *pos == coord!(3, 0),
+        );
+
+        assert!(result.is_none());
+    }
+}
@@ -0,0 +1,216 @@
+use std::{collections::HashMap, fmt};
+
+use crate::{
+    coord,
+    coord::Coord,
+    directions::{CompassDirection, Direction},
+    grid::{Grid, GridTile},
+};
+
+/// An unbounded grid where only set tiles are stored, everything else reads as the default tile
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    tiles: HashMap<Coord, T>,
+    default: T,
+    bounds: Option<(Coord, Coord)>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new(default: T) -> Self {
+        Self {
+            tiles: HashMap::new(),
+            default,
+            bounds: None,
+        }
+    }
+
+    /// Get at coordinate, the default tile if it has never been set
+    pub fn get(&self, coord: &Coord) -> &T {
+        self.tiles.get(coord).unwrap_or(&self.default)
+    }
+
+    /// Will return none if the tile has never been set
+    pub fn try_get(&self, coord: &Coord) -> Option<&T> {
+        self.tiles.get(coord)
+    }
+
+    /// Get a mutable tile, setting it to the default first if needed
+    pub fn get_mut(&mut self, coord: Coord) -> &mut T
+    where
+        T: Clone,
+    {
+        self.grow_bounds(coord);
+        self.tiles.entry(coord).or_insert_with(|| self.default.clone())
+    }
+
+    pub fn set(&mut self, coord: Coord, tile: T) {
+        self.grow_bounds(coord);
+        self.tiles.insert(coord, tile);
+    }
+
+    /// Unset a tile so it reads as the default again. The bounding box never shrinks
+    pub fn remove(&mut self, coord: &Coord) -> Option<T> {
+        self.tiles.remove(coord)
+    }
+
+    pub fn contains(&self, coord: &Coord) -> bool {
+        self.tiles.contains_key(coord)
+    }
+
+    /// Number of tiles that have been set
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    pub fn default_tile(&self) -> &T {
+        &self.default
+    }
+
+    /// Every set tile with its coordinate, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (&Coord, &T)> {
+        self.tiles.iter()
+    }
+
+    /// The top left and bottom right corners (inclusive) of every coord ever set, none if nothing
+    /// has been set
+    pub fn bounds(&self) -> Option<(Coord, Coord)> {
+        self.bounds
+    }
+
+    fn grow_bounds(&mut self, coord: Coord) {
+        self.bounds = Some(match self.bounds {
+            None => (coord, coord),
+            Some((min, max)) => (
+                coord!(min.x.min(coord.x), min.y.min(coord.y)),
+                coord!(max.x.max(coord.x), max.y.max(coord.y)),
+            ),
+        });
+    }
+
+    /// The four orthogonal neighbours, the grid is unbounded so these always exist
+    pub fn neighbours(&self, current: Coord) -> impl Iterator<Item = Coord> {
+        Direction::deltas().map(move |delta| current + delta)
+    }
+
+    /// All eight neighbours including diagonals
+    pub fn neighbours8(&self, current: Coord) -> impl Iterator<Item = Coord> {
+        CompassDirection::deltas().map(move |delta| current + delta)
+    }
+
+    /// Copy the bounding box into a dense grid, also returning the coord of the dense grid's 0,0
+    /// so positions can be mapped back. None if nothing has been set
+    pub fn to_grid(&self) -> Option<(Grid<T>, Coord)>
+    where
+        T: Clone,
+    {
+        let (min, max) = self.bounds?;
+        let width = (max.x - min.x + 1) as usize;
+        let height = (max.y - min.y + 1) as usize;
+
+        let mut grid = Grid::init(self.default.clone(), width, height);
+        for (coord, tile) in self.tiles.iter() {
+            grid.set(coord!(coord.x - min.x, coord.y - min.y), tile.clone());
+        }
+
+        Some((grid, min))
+    }
+
+    /// Draw the bounding box one row per line, top row first
+    pub fn render(&self, to_char: impl Fn(&T) -> char) -> String {
+        let Some((min, max)) = self.bounds else {
+            return String::new();
+        };
+
+        let mut out = String::new();
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                out.push(to_char(self.get(&coord!(x, y))));
+            }
+            out.push('\n');
+        }
+
+        out
+    }
+}
+
+impl<T: PartialEq> SparseGrid<T> {
+    /// Take every tile from a dense grid that isn't the default
+    pub fn from_grid(grid: Grid<T>, default: T) -> Self {
+        let mut sparse = Self::new(default);
+        for (coord, tile) in grid.into_tiles() {
+            if tile != sparse.default {
+                sparse.set(coord, tile);
+            }
+        }
+
+        sparse
+    }
+}
+
+/// The bounding box drawn one row per line
+impl<T: GridTile> fmt::Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render(T::to_char))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unset_tiles_read_default() {
+        let mut grid = SparseGrid::new('.');
+        grid.set(coord!(-3, 5), '#');
+
+        assert_eq!(grid.get(&coord!(-3, 5)), &'#');
+        assert_eq!(grid.get(&coord!(100, -100)), &'.');
+        assert_eq!(grid.try_get(&coord!(100, -100)), None);
+        assert_eq!(grid.len(), 1);
+    }
+
+    #[test]
+    fn bounds_track_every_set() {
+        let mut grid = SparseGrid::new(0);
+        assert_eq!(grid.bounds(), None);
+
+        grid.set(coord!(2, -1), 1);
+        grid.set(coord!(-4, 3), 1);
+        *grid.get_mut(coord!(0, 7)) += 5;
+        grid.remove(&coord!(2, -1));
+
+        assert_eq!(grid.bounds(), Some((coord!(-4, -1), coord!(2, 7))));
+        assert_eq!(grid.get(&coord!(0, 7)), &5);
+    }
+
+    #[test]
+    fn dense_round_trip() {
+        let mut sparse = SparseGrid::new('.');
+        sparse.set(coord!(-1, -1), '#');
+        sparse.set(coord!(1, 0), '#');
+
+        let (dense, offset) = sparse.to_grid().unwrap();
+        assert_eq!(offset, coord!(-1, -1));
+        assert_eq!((dense.width, dense.height), (3, 2));
+        assert_eq!(dense.get(&coord!(2, 1)), &'#');
+
+        let back = SparseGrid::from_grid(dense, '.');
+        assert_eq!(back.len(), 2);
+        assert_eq!(back.get(&coord!(2, 1)), &'#');
+    }
+
+    #[test]
+    fn render_bounding_box() {
+        let mut grid = SparseGrid::new(false);
+        grid.set(coord!(0, 0), true);
+        grid.set(coord!(2, 1), true);
+
+        let text = grid.render(|&on| if on { '#' } else { ' ' });
+        assert_eq!(text, "#  \n  #\n");
+        assert_eq!(grid.to_string(), "#..\n..#\n");
+    }
+}